@@ -0,0 +1,243 @@
+use anyhow::*;
+use num_bigint::BigInt;
+use serde::de::{self, Deserializer, Visitor};
+use serde::Deserialize;
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+/// The answer to one part of a puzzle.
+///
+/// Answers compare by their normalized textual form, so `Unsigned(5)`, `Signed(5)` and
+/// `Text("5")` are all equal, and ASCII art ignores trailing whitespace.
+#[derive(Debug, Clone)]
+pub enum Answer {
+    Unsigned(u64),
+    Signed(i64),
+    Big(BigInt),
+    Text(String),
+    /// Multi-line ASCII art, one entry per line.
+    Art(Vec<String>),
+}
+
+impl Answer {
+    pub fn is_multiline(&self) -> bool {
+        matches!(self, Answer::Art(lines) if lines.len() > 1)
+    }
+
+    fn normalized(&self) -> String {
+        match self {
+            Answer::Text(text) => text.trim().to_string(),
+            Answer::Art(lines) => {
+                let mut lines = lines.iter().map(|l| l.trim_end()).collect::<Vec<_>>();
+                while lines.last().is_some_and(|l| l.is_empty()) {
+                    lines.pop();
+                }
+                lines.join("\n")
+            }
+            _ => self.to_string(),
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Unsigned(value) => write!(f, "{}", value),
+            Answer::Signed(value) => write!(f, "{}", value),
+            Answer::Big(value) => write!(f, "{}", value),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Art(lines) => write!(f, "{}", lines.join("\n")),
+        }
+    }
+}
+
+impl FromStr for Answer {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let trimmed = s.trim_matches('\n');
+        if trimmed.trim().is_empty() {
+            bail!("empty answer");
+        }
+
+        if trimmed.contains('\n') {
+            return Ok(Answer::Art(trimmed.lines().map(str::to_string).collect()));
+        }
+
+        let trimmed = trimmed.trim();
+        if let Result::Ok(value) = trimmed.parse::<u64>() {
+            Ok(Answer::Unsigned(value))
+        } else if let Result::Ok(value) = trimmed.parse::<i64>() {
+            Ok(Answer::Signed(value))
+        } else if let Result::Ok(value) = trimmed.parse::<BigInt>() {
+            Ok(Answer::Big(value))
+        } else {
+            Ok(Answer::Text(trimmed.to_string()))
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        self.normalized() == other.normalized()
+    }
+}
+
+impl Eq for Answer {}
+
+impl Hash for Answer {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.normalized().hash(state);
+    }
+}
+
+macro_rules! impl_integer {
+    ($variant:ident($target:ty): $($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::$variant(value as $target)
+                }
+            }
+
+            impl PartialEq<$t> for Answer {
+                fn eq(&self, other: &$t) -> bool {
+                    self.normalized() == other.to_string()
+                }
+            }
+
+            impl PartialEq<Answer> for $t {
+                fn eq(&self, other: &Answer) -> bool {
+                    other == self
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(Unsigned(u64): u8, u16, u32, u64, usize);
+impl_integer!(Signed(i64): i8, i16, i32, i64, isize);
+
+impl From<u128> for Answer {
+    fn from(value: u128) -> Self {
+        u64::try_from(value)
+            .map(Answer::Unsigned)
+            .unwrap_or_else(|_| Answer::Big(value.into()))
+    }
+}
+
+impl From<i128> for Answer {
+    fn from(value: i128) -> Self {
+        i64::try_from(value)
+            .map(Answer::Signed)
+            .unwrap_or_else(|_| Answer::Big(value.into()))
+    }
+}
+
+impl From<BigInt> for Answer {
+    fn from(value: BigInt) -> Self {
+        Answer::Big(value)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        if value.contains('\n') {
+            Answer::Art(value.lines().map(str::to_string).collect())
+        } else {
+            Answer::Text(value)
+        }
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        value.to_string().into()
+    }
+}
+
+impl From<Vec<String>> for Answer {
+    fn from(lines: Vec<String>) -> Self {
+        Answer::Art(lines)
+    }
+}
+
+/// Answers files may hold either a TOML integer or a string (for text, art or values wider than `i64`).
+impl<'de> Deserialize<'de> for Answer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        struct AnswerVisitor;
+
+        impl Visitor<'_> for AnswerVisitor {
+            type Value = Answer;
+
+            fn expecting(&self, f: &mut Formatter) -> std::fmt::Result {
+                write!(f, "an integer or a string")
+            }
+
+            fn visit_i64<E: de::Error>(self, value: i64) -> std::result::Result<Answer, E> {
+                Result::Ok(value.into())
+            }
+
+            fn visit_u64<E: de::Error>(self, value: u64) -> std::result::Result<Answer, E> {
+                Result::Ok(value.into())
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> std::result::Result<Answer, E> {
+                value.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_any(AnswerVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integers_compare_across_variants() {
+        assert_eq!(Answer::Unsigned(42), Answer::Signed(42));
+        assert_eq!(Answer::Big(42.into()), Answer::Unsigned(42));
+        assert_eq!(Answer::Text("42".to_string()), Answer::Signed(42));
+        assert_ne!(Answer::Signed(-42), Answer::Unsigned(42));
+        assert_eq!(42, Answer::from(42usize));
+        assert_eq!(Answer::from(-1i32), -1);
+    }
+
+    #[test]
+    fn parses_the_narrowest_variant() -> Result<()> {
+        assert!(matches!(
+            "3121910778619".parse()?,
+            Answer::Unsigned(3121910778619)
+        ));
+        assert!(matches!("-7".parse()?, Answer::Signed(-7)));
+        assert!(matches!(
+            "340282366920938463463374607431768211456".parse()?,
+            Answer::Big(_)
+        ));
+        assert!(matches!("abc,def".parse()?, Answer::Text(_)));
+        assert!("  \n".parse::<Answer>().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn wide_integers_become_big() {
+        let product = u64::MAX as u128 * 3;
+        assert!(matches!(Answer::from(product), Answer::Big(_)));
+        assert!(matches!(Answer::from(12u128), Answer::Unsigned(12)));
+        assert_eq!(product.to_string(), Answer::from(product).to_string());
+    }
+
+    #[test]
+    fn art_round_trips_and_ignores_trailing_whitespace() -> Result<()> {
+        let art = Answer::from(vec!["#..#".to_string(), "####  ".to_string()]);
+        assert!(art.is_multiline());
+
+        let parsed: Answer = "\n#..#\n####\n".parse()?;
+        assert_eq!(art, parsed);
+        assert_eq!(parsed, art.to_string().parse::<Answer>()?);
+        Ok(())
+    }
+}
@@ -0,0 +1,93 @@
+use crate::{Answer, Part};
+use anyhow::*;
+use serde::Deserialize;
+use std::fs;
+use std::path::Path;
+
+/// Expected answers for both parts of one input.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Expected {
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+}
+
+impl Expected {
+    pub fn get(&self, part: Part) -> Option<&Answer> {
+        match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        }
+    }
+}
+
+/// The `answers/NN.toml` file of a day, with the known answers for the real input.
+///
+/// Example answers are declared next to the example inputs, see [`crate::Example`].
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AnswerSheet {
+    #[serde(default)]
+    pub real: Expected,
+}
+
+impl AnswerSheet {
+    pub fn path(day: u8) -> String {
+        format!("answers/{:02}.toml", day)
+    }
+
+    /// Loads the answers of `day`, or an empty sheet when the day has no answers file yet.
+    pub fn load(day: u8) -> Result<Self> {
+        let path = Self::path(day);
+        if !Path::new(&path).exists() {
+            return Ok(Self::default());
+        }
+
+        let text = fs::read_to_string(&path).with_context(|| format!("failed to read {}", path))?;
+        text.parse()
+            .with_context(|| format!("invalid answers file {}", path))
+    }
+}
+
+impl std::str::FromStr for AnswerSheet {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(toml::from_str(s)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_integers_and_strings() -> Result<()> {
+        let sheet: AnswerSheet = r#"
+            [real]
+            part1 = 3
+            part2 = "340282366920938463463374607431768211456"
+        "#
+        .parse()?;
+
+        assert_eq!(Some(&Answer::Unsigned(3)), sheet.real.get(Part::One));
+        assert!(matches!(sheet.real.part2, Some(Answer::Big(_))));
+
+        let sheet: AnswerSheet = "[real]\npart1 = \"ABCDEF\"".parse()?;
+        assert_eq!(Some(&Answer::from("ABCDEF")), sheet.real.get(Part::One));
+        assert_eq!(None, sheet.real.get(Part::Two));
+        Ok(())
+    }
+
+    #[test]
+    fn sections_are_optional() -> Result<()> {
+        assert_eq!(AnswerSheet::default(), "".parse()?);
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_unknown_keys() {
+        assert!("[real]\npart3 = 1".parse::<AnswerSheet>().is_err());
+        assert!("[example]\npart1 = 1".parse::<AnswerSheet>().is_err());
+    }
+}
@@ -0,0 +1,215 @@
+use adv_code_2025::*;
+use anyhow::*;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::PathBuf;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+pub const HISTORY_FILE: &str = "bench_history.json";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Options {
+    pub warmup: usize,
+    pub iterations: usize,
+}
+
+/// Summary of the samples of one measurement, in nanoseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    pub min: u64,
+    pub median: u64,
+    pub p95: u64,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        let mut nanos = samples
+            .iter()
+            .map(|sample| sample.as_nanos() as u64)
+            .collect::<Vec<_>>();
+        nanos.sort_unstable();
+
+        // Nearest-rank percentile, so every statistic is an actual sample
+        let percentile = |p: usize| nanos[(nanos.len() * p).div_ceil(100).max(1) - 1];
+        Some(Self {
+            min: *nanos.first()?,
+            median: percentile(50),
+            p95: percentile(95),
+        })
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "min {:>10.2?}  median {:>10.2?}  p95 {:>10.2?}",
+            Duration::from_nanos(self.min),
+            Duration::from_nanos(self.median),
+            Duration::from_nanos(self.p95)
+        )
+    }
+}
+
+/// One benchmarked part, as stored in the history file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Record {
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+    pub day: u8,
+    pub part: u8,
+    pub params: String,
+    pub input_sha256: String,
+    pub warmup: usize,
+    pub iterations: usize,
+    /// Time spent parsing the input, missing from records made before parsing was timed separately.
+    pub parse: Option<Stats>,
+    pub solve: Stats,
+}
+
+impl Record {
+    /// Whether `other` measured the same thing, so that both can be compared.
+    fn is_comparable(&self, other: &Record) -> bool {
+        self.day == other.day
+            && self.part == other.part
+            && self.params == other.params
+            && self.input_sha256 == other.input_sha256
+    }
+}
+
+/// Times `options.iterations` runs of one part after `options.warmup` untimed ones, parsing and solving apart.
+pub fn bench_part(
+    solution: &dyn Puzzle,
+    part: Part,
+    input: &str,
+    params: &Params,
+    options: Options,
+) -> Result<Record> {
+    for _ in 0..options.warmup {
+        solution.solve(part, input, params)?;
+    }
+
+    let mut parse_samples = Vec::with_capacity(options.iterations);
+    let mut solve_samples = Vec::with_capacity(options.iterations);
+    for _ in 0..options.iterations {
+        let start = Instant::now();
+        let parsed = std::hint::black_box(solution.parse(input)?);
+        parse_samples.push(start.elapsed());
+
+        let start = Instant::now();
+        std::hint::black_box(solution.solve_parsed(part, &parsed, params)?);
+        solve_samples.push(start.elapsed());
+    }
+
+    Ok(Record {
+        timestamp: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs(),
+        day: solution.day(),
+        part: part.number(),
+        params: params.to_string(),
+        input_sha256: Cache::hash(input),
+        warmup: options.warmup,
+        iterations: options.iterations,
+        parse: Stats::from_samples(&parse_samples),
+        solve: Stats::from_samples(&solve_samples)
+            .ok_or_else(|| anyhow!("no iteration was run"))?,
+    })
+}
+
+/// JSON file keeping every benchmark run, oldest first.
+pub struct History {
+    path: PathBuf,
+    records: Vec<Record>,
+}
+
+impl History {
+    pub fn load(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let records = match fs::read_to_string(&path) {
+            Result::Ok(text) => serde_json::from_str(&text)
+                .with_context(|| format!("failed to parse {}", path.display()))?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+            Err(e) => return Err(e).context(format!("failed to read {}", path.display())),
+        };
+        Ok(Self { path, records })
+    }
+
+    /// The latest record measuring the same day, part, params and input as `record`.
+    pub fn previous(&self, record: &Record) -> Option<&Record> {
+        self.records.iter().rev().find(|r| r.is_comparable(record))
+    }
+
+    pub fn push(&mut self, record: Record) {
+        self.records.push(record);
+    }
+
+    pub fn save(&self) -> Result<()> {
+        fs::write(&self.path, serde_json::to_string_pretty(&self.records)?)
+            .with_context(|| format!("failed to write {}", self.path.display()))
+    }
+}
+
+/// Relative change of the median from `previous` to `current`, e.g. "+12.5%".
+pub fn median_change(previous: &Record, current: &Record) -> String {
+    let previous = previous.solve.median as f64;
+    let current = current.solve.median as f64;
+    format!("{:+.1}%", (current - previous) / previous * 100.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::Lines;
+
+    #[test]
+    fn computes_stats() {
+        let samples = (1..=20).rev().map(Duration::from_nanos).collect::<Vec<_>>();
+        assert_eq!(
+            Some(Stats {
+                min: 1,
+                median: 10,
+                p95: 19
+            }),
+            Stats::from_samples(&samples)
+        );
+        assert_eq!(
+            Some(Stats {
+                min: 7,
+                median: 7,
+                p95: 7
+            }),
+            Stats::from_samples(&[Duration::from_nanos(7)])
+        );
+        assert_eq!(None, Stats::from_samples(&[]));
+    }
+
+    #[test]
+    fn compares_with_the_previous_run() -> Result<()> {
+        let path = std::env::temp_dir().join(format!("aoc-bench-{}.json", std::process::id()));
+        let _ = fs::remove_file(&path);
+        let options = Options {
+            warmup: 1,
+            iterations: 3,
+        };
+
+        let mut history = History::load(&path)?;
+        let first = bench_part(&Lines, Part::One, "a\nb\n", &Params::default(), options)?;
+        assert_eq!(None, history.previous(&first));
+        assert!(first.parse.is_some());
+        history.push(first.clone());
+        history.save()?;
+
+        let history = History::load(&path)?;
+        let second = bench_part(&Lines, Part::One, "a\nb\n", &Params::default(), options)?;
+        assert_eq!(Some(&first), history.previous(&second));
+        let other = bench_part(&Lines, Part::One, "c\n", &Params::default(), options)?;
+        assert_eq!(None, history.previous(&other));
+
+        assert!(bench_part(&Lines, Part::Two, "a\n", &Params::default(), options).is_err());
+        fs::remove_file(&path)?;
+        Ok(())
+    }
+}
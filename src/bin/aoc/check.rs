@@ -0,0 +1,200 @@
+use crate::isolate::isolated;
+use adv_code_2025::*;
+use anyhow::*;
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+use std::time::Duration;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail(String),
+    Unknown(String),
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Pass => write!(f, "pass"),
+            Status::Fail(reason) => write!(f, "FAIL ({})", reason),
+            Status::Unknown(reason) => write!(f, "unknown ({})", reason),
+        }
+    }
+}
+
+/// Compares the `answer` of one part with the `expected` one.
+pub fn verify(answer: Result<Answer>, expected: Option<&Answer>) -> Status {
+    // Only a recorded answer can fail, so unsolved parts stay unknown
+    match (answer, expected) {
+        (Result::Ok(answer), Some(expected)) if answer == *expected => Status::Pass,
+        (Result::Ok(answer), Some(expected)) => {
+            Status::Fail(format!("expected {}, got {}", expected, answer))
+        }
+        (Err(e), Some(_)) => Status::Fail(format!("{:#}", e)),
+        (Result::Ok(answer), None) => {
+            Status::Unknown(format!("no answer recorded, got {}", answer))
+        }
+        (Err(e), None) => Status::Unknown(format!("no answer recorded, {:#}", e)),
+    }
+}
+
+/// Checks one part of an example in isolation, so that a panic or a `timeout` only fails this example.
+pub fn verify_example(
+    solution: &'static dyn Puzzle,
+    example: &Example,
+    part: Part,
+    timeout: Option<Duration>,
+) -> Status {
+    let params = match example.resolve_params(solution) {
+        Result::Ok(params) => params,
+        Err(e) => return Status::Fail(format!("{:#}", e)),
+    };
+    let input = example.input;
+    let answer = isolated(timeout, move || solution.solve(part, input, &params));
+    verify(answer, example.expected(part))
+}
+
+#[derive(Debug, Default)]
+pub struct Summary {
+    pub passed: usize,
+    pub failed: usize,
+    pub unknown: usize,
+}
+
+impl Summary {
+    fn record(&mut self, status: &Status) {
+        match status {
+            Status::Pass => self.passed += 1,
+            Status::Fail(_) => self.failed += 1,
+            Status::Unknown(_) => self.unknown += 1,
+        }
+    }
+}
+
+impl Display for Summary {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} passed, {} failed, {} unknown",
+            self.passed, self.failed, self.unknown
+        )
+    }
+}
+
+fn report(day: u8, part: Part, label: &str, status: Status, summary: &mut Summary) {
+    println!("Day {:02} part {}  {:<20} {}", day, part, label, status);
+    summary.record(&status);
+}
+
+/// Re-runs the examples and the real input of each part, comparing the real answers with `answers/NN.toml`.
+pub fn check_day(
+    solution: &'static dyn Puzzle,
+    parts: &[Part],
+    provider: &InputProvider,
+    params: &Params,
+    summary: &mut Summary,
+) -> Result<()> {
+    let day = solution.day();
+    let sheet = AnswerSheet::load(day)?;
+
+    // The real input is parsed once, then each part is solved from it, all in isolation
+    let parsed = match provider.load(day) {
+        Result::Ok(input) => Some(isolated(None, move || solution.parse(&input)).map(Arc::new)),
+        Err(e) if e.is::<MissingInput>() => None,
+        Err(e) => return Err(e),
+    };
+
+    let examples = solution.examples();
+    for &part in parts {
+        // An example without an answer for this part is not meant for it (e.g. Day 11 uses one per part)
+        for example in examples.iter().filter(|e| e.expected(part).is_some()) {
+            let status = verify_example(solution, example, part, None);
+            report(
+                day,
+                part,
+                &format!("example {}", example.name),
+                status,
+                summary,
+            );
+        }
+
+        let real = match &parsed {
+            Some(Result::Ok(parsed)) => {
+                let (parsed, params) = (Arc::clone(parsed), params.clone());
+                verify(
+                    isolated(None, move || solution.solve_parsed(part, &parsed, &params)),
+                    sheet.real.get(part),
+                )
+            }
+            Some(Err(e)) => verify(Err(anyhow!("{:#}", e)), sheet.real.get(part)),
+            None => Status::Unknown("no input".to_string()),
+        };
+        report(day, part, "real", real, summary);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{text, Lines};
+
+    #[test]
+    fn reports_each_status() {
+        let parsed = Puzzle::parse(&Lines, "a\nb\n").unwrap();
+        let solve = |part| Lines.solve_parsed(part, &parsed, &Params::default());
+        assert_eq!(Status::Pass, verify(solve(Part::One), Some(&2.into())));
+        assert_eq!(
+            Status::Fail("expected 3, got 2".to_string()),
+            verify(solve(Part::One), Some(&3.into()))
+        );
+        assert_eq!(
+            Status::Unknown("no answer recorded, got 2".to_string()),
+            verify(solve(Part::One), None)
+        );
+        assert!(matches!(
+            verify(solve(Part::Two), Some(&2.into())),
+            Status::Fail(_)
+        ));
+        assert!(matches!(verify(solve(Part::Two), None), Status::Unknown(_)));
+    }
+
+    #[test]
+    fn examples_with_bad_params_fail() {
+        let example = Example::new("typo", "a\n").part1(1).param("size", 3);
+        assert!(matches!(
+            verify_example(&Lines, &example, Part::One, None),
+            Status::Fail(_)
+        ));
+        assert_eq!(
+            Status::Pass,
+            verify_example(&Lines, &Example::new("ok", "a\n").part1(1), Part::One, None)
+        );
+    }
+
+    #[test]
+    fn examples_that_panic_fail() {
+        let example = Example::new("panics", "?\n").part1(1);
+        assert_eq!(
+            Status::Fail("panicked: unreadable line".to_string()),
+            verify_example(&Lines, &example, Part::One, None)
+        );
+    }
+
+    #[test]
+    fn a_panicking_real_input_does_not_stop_the_check() -> Result<()> {
+        let mut summary = Summary::default();
+        let provider = text("?\n");
+        check_day(
+            &Lines,
+            &Part::ALL,
+            &provider,
+            &Params::default(),
+            &mut summary,
+        )?;
+        // The wrong example fails, and both parts are unknown without a recorded answer
+        assert_eq!((1, 1, 2), (summary.passed, summary.failed, summary.unknown));
+        Ok(())
+    }
+}
@@ -0,0 +1,450 @@
+use adv_code_2025::{render, Part};
+use anyhow::*;
+use std::time::Duration;
+
+pub const USAGE: &str = "\
+Usage: aoc [run] <DAY|all> [--part <1|2>] [--input <DIR|-> | --examples] [--param NAME=VALUE]...
+           [--format <text|json>] [--timeout SECONDS] [--verbose] [--trace <DAY>]...
+           [--render <DAY>]... [--render-format <ansi|ppm|png|gif>] [--render-dir DIR]
+       aoc check [DAY|all] [--part <1|2>] [--input <DIR|->] [--param NAME=VALUE]...
+       aoc bench [DAY|all] [PART] [--iterations N] [--warmup N] [--input <DIR|->] [--param NAME=VALUE]...
+       aoc new <DAY>
+
+Inputs are read from $AOC_INPUT_NN, then input/NN.txt, then downloaded when
+AOC_SESSION is set (from AOC_BASE_URL, https://adventofcode.com by default).
+--input reads NN.txt from another directory instead, or standard input with '-'.
+--examples runs the examples declared by each day instead of the puzzle input.
+--param overrides a puzzle parameter for the real input; examples keep their own.
+--format json prints one JSON object per line and part instead, with the answer,
+timings, input hash, example statuses and error.
+Days run in parallel, and a panic only fails its own part. --timeout stops
+waiting for a parse or a part after SECONDS; all ends with a summary table.
+--verbose prints the debug diagnostics of every day to standard error, and
+--trace also prints the detailed ones of a day.
+--render records the snapshots of a day and writes them under DIR/dayNN/ (render/
+by default), as colored text, PPM or PNG images (the default), or a GIF animation.
+bench times the real input (1 warm-up run and 10 timed runs by default) and
+appends the results to bench_history.json.
+new creates src/days/dayNN.rs from template/NN.rs and registers it, along with
+an empty input/NN.txt and answers/NN.toml.
+
+Examples:
+  aoc 7            run both parts of day 7
+  aoc 7 --part 2   run only part 2 of day 7
+  aoc all          run every registered day
+  aoc 11 -e        run the examples of day 11
+  aoc 8 --param connect=2000
+  aoc run all --format json
+  aoc all --timeout 30
+  aoc 1 -p 2 --trace 1
+  aoc 4 -p 2 --render 4 --render-format gif
+  aoc check        compare every day against answers/NN.toml
+  aoc bench 9 2 -n 3
+  aoc new 13";
+
+pub const DEFAULT_RENDER_DIR: &str = "render";
+const DEFAULT_WARMUP: usize = 1;
+const DEFAULT_ITERATIONS: usize = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Run,
+    Check,
+    Bench,
+    New,
+}
+
+/// How `run` prints its results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    /// One JSON object per line and part.
+    Json,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Selection {
+    Day(u8),
+    All,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Args {
+    pub command: Command,
+    pub selection: Selection,
+    pub part: Option<Part>,
+    pub input: Option<String>,
+    pub examples: bool,
+    pub params: Vec<(String, String)>,
+    pub format: Format,
+    /// How long `run` waits for each parse and part.
+    pub timeout: Option<Duration>,
+    pub warmup: usize,
+    pub iterations: usize,
+    pub verbose: bool,
+    pub trace: Vec<u8>,
+    pub render: Vec<u8>,
+    pub render_format: render::Format,
+    pub render_dir: String,
+}
+
+impl Args {
+    pub fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(part) => vec![part],
+            None => Part::ALL.to_vec(),
+        }
+    }
+}
+
+pub fn parse_args<I: IntoIterator<Item = String>>(args: I) -> Result<Args> {
+    let mut command = Command::Run;
+    let mut selection = None;
+    let mut part = None;
+    let mut input = None;
+    let mut examples = false;
+    let mut params = Vec::new();
+    let mut format = Format::Text;
+    let mut timeout = None;
+    let mut warmup = DEFAULT_WARMUP;
+    let mut iterations = DEFAULT_ITERATIONS;
+    let mut verbose = false;
+    let mut trace = Vec::new();
+    let mut render = Vec::new();
+    let mut render_format = render::Format::Png;
+    let mut render_dir = DEFAULT_RENDER_DIR.to_string();
+
+    let mut args = args.into_iter().peekable();
+    let named = match args.peek().map(String::as_str) {
+        Some("run") => Some(Command::Run),
+        Some("check") => Some(Command::Check),
+        Some("bench") => Some(Command::Bench),
+        Some("new") => Some(Command::New),
+        _ => None,
+    };
+    if let Some(named) = named {
+        command = named;
+        args.next();
+    }
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" | "-p" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| anyhow!("--part expects a value"))?;
+                part = Some(value.parse()?);
+            }
+            "--input" | "-i" => {
+                input = Some(
+                    args.next()
+                        .ok_or_else(|| anyhow!("--input expects a directory or '-'"))?,
+                );
+            }
+            "--examples" | "-e" => examples = true,
+            "--param" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| anyhow!("--param expects NAME=VALUE"))?;
+                let (name, value) = value
+                    .split_once('=')
+                    .ok_or_else(|| anyhow!("--param expects NAME=VALUE, got '{}'", value))?;
+                params.push((name.trim().to_string(), value.trim().to_string()));
+            }
+            "--format" | "-f" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| anyhow!("--format expects text or json"))?;
+                format = match value.as_str() {
+                    "text" => Format::Text,
+                    "json" => Format::Json,
+                    _ => bail!("invalid format '{}', expected text or json", value),
+                };
+            }
+            "--timeout" | "-t" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| anyhow!("--timeout expects a number of seconds"))?;
+                timeout = Some(
+                    value
+                        .parse::<f64>()
+                        .ok()
+                        .filter(|&seconds| seconds > 0.0)
+                        .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+                        .ok_or_else(|| anyhow!("invalid timeout '{}'", value))?,
+                );
+            }
+            "--warmup" | "-w" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| anyhow!("--warmup expects a number"))?;
+                warmup = value
+                    .parse()
+                    .map_err(|_| anyhow!("invalid warm-up count '{}'", value))?;
+            }
+            "--iterations" | "-n" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| anyhow!("--iterations expects a number"))?;
+                iterations = value
+                    .parse()
+                    .ok()
+                    .filter(|&n| n > 0)
+                    .ok_or_else(|| anyhow!("invalid iteration count '{}'", value))?;
+            }
+            "--verbose" | "-v" => verbose = true,
+            "--trace" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| anyhow!("--trace expects a day"))?;
+                trace.push(
+                    value
+                        .parse::<u8>()
+                        .map_err(|_| anyhow!("invalid day '{}'", value))?,
+                );
+            }
+            "--render" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| anyhow!("--render expects a day"))?;
+                render.push(
+                    value
+                        .parse::<u8>()
+                        .map_err(|_| anyhow!("invalid day '{}'", value))?,
+                );
+            }
+            "--render-format" => {
+                render_format = args
+                    .next()
+                    .ok_or_else(|| anyhow!("--render-format expects ansi, ppm, png or gif"))?
+                    .parse()?;
+            }
+            "--render-dir" => {
+                render_dir = args
+                    .next()
+                    .ok_or_else(|| anyhow!("--render-dir expects a directory"))?;
+            }
+            "all" if selection.is_none() => selection = Some(Selection::All),
+            // `aoc bench 9 2` benchmarks part 2 of day 9
+            _ if command == Command::Bench && selection.is_some() && part.is_none() => {
+                part = Some(arg.parse()?);
+            }
+            _ if arg.starts_with('-') => bail!("unknown option '{}'", arg),
+            _ if selection.is_some() => {
+                bail!("unexpected '{}', only one day can be selected", arg)
+            }
+            _ => {
+                let day = arg
+                    .parse::<u8>()
+                    .map_err(|_| anyhow!("invalid day '{}'", arg))?;
+                selection = Some(Selection::Day(day));
+            }
+        }
+    }
+
+    if format == Format::Json && command != Command::Run {
+        bail!("--format json is only supported by run");
+    }
+    if timeout.is_some() && command != Command::Run {
+        bail!("--timeout is only supported by run");
+    }
+    if !render.is_empty() && command != Command::Run {
+        bail!("--render is only supported by run");
+    }
+
+    let selection = match (command, selection) {
+        (Command::New, Some(Selection::All)) => bail!("new expects a single day"),
+        (_, Some(selection)) => selection,
+        (Command::Check | Command::Bench, None) => Selection::All,
+        (Command::Run | Command::New, None) => bail!("missing day"),
+    };
+
+    Ok(Args {
+        command,
+        selection,
+        part,
+        input,
+        examples,
+        params,
+        format,
+        timeout,
+        warmup,
+        iterations,
+        verbose,
+        trace,
+        render,
+        render_format,
+        render_dir,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Args> {
+        parse_args(args.iter().map(|s| s.to_string()))
+    }
+
+    #[test]
+    fn parses_day_and_part() -> Result<()> {
+        let args = parse(&["7", "--part", "2"])?;
+        assert_eq!(Command::Run, args.command);
+        assert_eq!(Selection::Day(7), args.selection);
+        assert_eq!(vec![Part::Two], args.parts());
+        assert_eq!(None, args.input);
+        Ok(())
+    }
+
+    #[test]
+    fn parses_input_override() -> Result<()> {
+        assert_eq!(Some("-".to_string()), parse(&["3", "--input", "-"])?.input);
+        assert_eq!(
+            Some("alt".to_string()),
+            parse(&["check", "-i", "alt"])?.input
+        );
+        assert!(parse(&["3", "--input"]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn parses_examples_flag() -> Result<()> {
+        assert!(parse(&["11", "--examples"])?.examples);
+        assert!(parse(&["all", "-e"])?.examples);
+        assert!(!parse(&["11"])?.examples);
+        Ok(())
+    }
+
+    #[test]
+    fn parses_params() -> Result<()> {
+        let args = parse(&["8", "--param", "connect=2000", "--param", "x = y"])?;
+        assert_eq!(
+            vec![
+                ("connect".to_string(), "2000".to_string()),
+                ("x".to_string(), "y".to_string())
+            ],
+            args.params
+        );
+        assert!(parse(&["8", "--param", "connect"]).is_err());
+        assert!(parse(&["8", "--param"]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn parses_log_flags() -> Result<()> {
+        let args = parse(&["all", "--verbose", "--trace", "1", "--trace", "10"])?;
+        assert!(args.verbose);
+        assert_eq!(vec![1, 10], args.trace);
+        assert!(!parse(&["1"])?.verbose);
+        assert!(parse(&["1", "--trace"]).is_err());
+        assert!(parse(&["1", "--trace", "x"]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn parses_format() -> Result<()> {
+        let args = parse(&["run", "all", "--format", "json"])?;
+        assert_eq!(Command::Run, args.command);
+        assert_eq!(Selection::All, args.selection);
+        assert_eq!(Format::Json, args.format);
+        assert_eq!(Format::Text, parse(&["7"])?.format);
+        assert_eq!(Format::Text, parse(&["run", "7", "-f", "text"])?.format);
+        assert!(parse(&["7", "--format", "xml"]).is_err());
+        assert!(parse(&["check", "--format", "json"]).is_err());
+        assert!(parse(&["run"]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn parses_timeout() -> Result<()> {
+        assert_eq!(None, parse(&["all"])?.timeout);
+        assert_eq!(
+            Some(Duration::from_millis(1500)),
+            parse(&["all", "--timeout", "1.5"])?.timeout
+        );
+        assert_eq!(
+            Some(Duration::from_secs(30)),
+            parse(&["run", "9", "-t", "30"])?.timeout
+        );
+        assert!(parse(&["all", "--timeout", "0"]).is_err());
+        assert!(parse(&["all", "--timeout", "soon"]).is_err());
+        assert!(parse(&["bench", "--timeout", "5"]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn parses_render() -> Result<()> {
+        let args = parse(&["4", "--render", "4", "--render-format", "gif"])?;
+        assert_eq!(vec![4], args.render);
+        assert_eq!(render::Format::Gif, args.render_format);
+        assert_eq!(DEFAULT_RENDER_DIR, args.render_dir);
+
+        let args = parse(&["all", "--render", "7", "--render-dir", "out"])?;
+        assert_eq!(render::Format::Png, args.render_format);
+        assert_eq!("out", args.render_dir);
+        assert!(parse(&["4", "--render-format", "bmp"]).is_err());
+        assert!(parse(&["check", "--render", "4"]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn parses_all() -> Result<()> {
+        let args = parse(&["all"])?;
+        assert_eq!(Selection::All, args.selection);
+        assert_eq!(vec![Part::One, Part::Two], args.parts());
+        Ok(())
+    }
+
+    #[test]
+    fn check_defaults_to_every_day() -> Result<()> {
+        let args = parse(&["check"])?;
+        assert_eq!(Command::Check, args.command);
+        assert_eq!(Selection::All, args.selection);
+
+        let args = parse(&["check", "9", "-p", "1"])?;
+        assert_eq!(Selection::Day(9), args.selection);
+        assert_eq!(vec![Part::One], args.parts());
+        Ok(())
+    }
+
+    #[test]
+    fn parses_bench() -> Result<()> {
+        let args = parse(&["bench"])?;
+        assert_eq!(Command::Bench, args.command);
+        assert_eq!(Selection::All, args.selection);
+        assert_eq!(
+            (DEFAULT_WARMUP, DEFAULT_ITERATIONS),
+            (args.warmup, args.iterations)
+        );
+
+        let args = parse(&["bench", "9", "2", "-n", "3", "--warmup", "0"])?;
+        assert_eq!(Selection::Day(9), args.selection);
+        assert_eq!(vec![Part::Two], args.parts());
+        assert_eq!((0, 3), (args.warmup, args.iterations));
+
+        assert!(parse(&["bench", "9", "3"]).is_err());
+        assert!(parse(&["bench", "9", "-n", "0"]).is_err());
+        assert!(parse(&["9", "2"]).is_err());
+        assert!(parse(&["check", "all", "3"]).is_err());
+        assert!(parse(&["9", "all"]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn parses_new() -> Result<()> {
+        let args = parse(&["new", "13"])?;
+        assert_eq!(Command::New, args.command);
+        assert_eq!(Selection::Day(13), args.selection);
+        assert!(parse(&["new"]).is_err());
+        assert!(parse(&["new", "all"]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_bad_input() {
+        assert!(parse(&[]).is_err());
+        assert!(parse(&["seven"]).is_err());
+        assert!(parse(&["7", "--part", "3"]).is_err());
+        assert!(parse(&["7", "--fast"]).is_err());
+    }
+}
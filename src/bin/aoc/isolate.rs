@@ -0,0 +1,90 @@
+use anyhow::*;
+use std::fmt::{Display, Formatter};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+/// Why a run stopped without giving its own answer or error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Interrupted {
+    Panicked(String),
+    TimedOut(Duration),
+}
+
+impl Display for Interrupted {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Interrupted::Panicked(message) => write!(f, "panicked: {}", message),
+            Interrupted::TimedOut(timeout) => write!(f, "timed out after {:.2?}", timeout),
+        }
+    }
+}
+
+impl std::error::Error for Interrupted {}
+
+/// Runs `f` on its own thread, turning a panic or a run longer than `timeout` into an [`Interrupted`] error.
+///
+/// A run that times out cannot be stopped: its thread is left to finish in the background.
+pub fn isolated<T: Send + 'static>(
+    timeout: Option<Duration>,
+    f: impl FnOnce() -> Result<T> + Send + 'static,
+) -> Result<T> {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let result = panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|payload| {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic".to_string());
+            Err(Interrupted::Panicked(message).into())
+        });
+        // The receiver is gone when the run timed out
+        let _ = sender.send(result);
+    });
+
+    match timeout {
+        Some(timeout) => match receiver.recv_timeout(timeout) {
+            Result::Ok(result) => result,
+            Err(RecvTimeoutError::Timeout) => Err(Interrupted::TimedOut(timeout).into()),
+            Err(RecvTimeoutError::Disconnected) => bail!("the run stopped without a result"),
+        },
+        None => receiver
+            .recv()
+            .map_err(|_| anyhow!("the run stopped without a result"))?,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn returns_results_and_errors() {
+        assert_eq!(3, isolated(None, || Ok(3)).unwrap());
+        let error =
+            isolated::<()>(Some(Duration::from_secs(5)), || bail!("bad input")).unwrap_err();
+        assert_eq!("bad input", error.to_string());
+    }
+
+    #[test]
+    fn catches_panics() {
+        let error = isolated::<()>(None, || panic!("index out of bounds")).unwrap_err();
+        assert_eq!(
+            Some(&Interrupted::Panicked("index out of bounds".to_string())),
+            error.downcast_ref()
+        );
+    }
+
+    #[test]
+    fn stops_waiting_after_the_timeout() {
+        let timeout = Duration::from_millis(10);
+        let error = isolated(Some(timeout), || {
+            thread::sleep(Duration::from_secs(1));
+            Ok(())
+        })
+        .unwrap_err();
+        assert_eq!(Some(&Interrupted::TimedOut(timeout)), error.downcast_ref());
+    }
+}
@@ -0,0 +1,196 @@
+use crate::check::{self, Status};
+use crate::isolate::isolated;
+use adv_code_2025::*;
+use serde::Serialize;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// One line of `aoc run --format json`: how one part of one day went.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PartRecord {
+    pub day: u8,
+    pub part: u8,
+    /// The answer on the real input, as text so that wide numbers and ASCII art survive.
+    pub answer: Option<String>,
+    /// Time spent parsing the real input, shared by both parts, when it parsed.
+    pub parse_ns: Option<u64>,
+    pub solve_ns: Option<u64>,
+    pub input_sha256: Option<String>,
+    pub examples: Vec<ExampleRecord>,
+    /// Why the real input could not be solved.
+    pub error: Option<String>,
+}
+
+impl PartRecord {
+    fn new(day: u8, part: Part) -> Self {
+        Self {
+            day,
+            part: part.number(),
+            answer: None,
+            parse_ns: None,
+            solve_ns: None,
+            input_sha256: None,
+            examples: Vec::new(),
+            error: None,
+        }
+    }
+
+    pub fn is_failure(&self) -> bool {
+        self.error.is_some() || self.examples.iter().any(|e| e.status == "fail")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ExampleRecord {
+    pub name: String,
+    /// `pass`, `fail` or `unknown`.
+    pub status: &'static str,
+    pub reason: Option<String>,
+}
+
+impl ExampleRecord {
+    fn new(name: &str, status: Status) -> Self {
+        let (status, reason) = match status {
+            Status::Pass => ("pass", None),
+            Status::Fail(reason) => ("fail", Some(reason)),
+            Status::Unknown(reason) => ("unknown", Some(reason)),
+        };
+        Self {
+            name: name.to_string(),
+            status,
+            reason,
+        }
+    }
+}
+
+/// Runs the examples of the requested parts, and the real input unless `provider` is `None`.
+///
+/// Everything runs in isolation, so a panic or a `timeout` fails an example or becomes the record's error.
+pub fn run_day(
+    solution: &'static dyn Puzzle,
+    parts: &[Part],
+    provider: Option<&InputProvider>,
+    params: &Params,
+    timeout: Option<Duration>,
+) -> Vec<PartRecord> {
+    let day = solution.day();
+    let examples = solution.examples();
+    let mut records = parts
+        .iter()
+        .map(|&part| {
+            let mut record = PartRecord::new(day, part);
+            record.examples = examples
+                .iter()
+                .filter(|e| e.expected(part).is_some())
+                .map(|e| {
+                    ExampleRecord::new(e.name, check::verify_example(solution, e, part, timeout))
+                })
+                .collect();
+            record
+        })
+        .collect::<Vec<_>>();
+
+    let Some(provider) = provider else {
+        return records;
+    };
+    let input = match provider.load(day) {
+        Result::Ok(input) => input,
+        Err(e) => {
+            for record in &mut records {
+                record.error = Some(format!("{:#}", e));
+            }
+            return records;
+        }
+    };
+
+    let hash = Cache::hash(&input);
+    let start = Instant::now();
+    let parsed = isolated(timeout, move || solution.parse(&input)).map(Arc::new);
+    let parse_ns = start.elapsed().as_nanos() as u64;
+    for (record, &part) in records.iter_mut().zip(parts) {
+        record.input_sha256 = Some(hash.clone());
+        let parsed = match &parsed {
+            Result::Ok(parsed) => {
+                record.parse_ns = Some(parse_ns);
+                Arc::clone(parsed)
+            }
+            Err(e) => {
+                record.error = Some(format!("{:#}", e));
+                continue;
+            }
+        };
+
+        let params = params.clone();
+        let start = Instant::now();
+        match isolated(timeout, move || {
+            solution.solve_parsed(part, &parsed, &params)
+        }) {
+            Result::Ok(answer) => {
+                record.solve_ns = Some(start.elapsed().as_nanos() as u64);
+                record.answer = Some(answer.to_string());
+            }
+            Err(e) => record.error = Some(format!("{:#}", e)),
+        }
+    }
+    records
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{text, Lines};
+    use anyhow::*;
+
+    #[test]
+    fn records_answers_and_examples() -> Result<()> {
+        let records = run_day(
+            &Lines,
+            &Part::ALL,
+            Some(&text("x\ny\n")),
+            &Params::default(),
+            None,
+        );
+        assert_eq!(2, records.len());
+
+        let part1 = &records[0];
+        assert_eq!((0, 1), (part1.day, part1.part));
+        assert_eq!(Some("2".to_string()), part1.answer);
+        assert_eq!(Some(Cache::hash("x\ny\n")), part1.input_sha256);
+        assert!(part1.parse_ns.is_some() && part1.solve_ns.is_some());
+        assert_eq!(
+            vec!["pass", "fail"],
+            part1.examples.iter().map(|e| e.status).collect::<Vec<_>>()
+        );
+        assert!(part1.is_failure());
+
+        let part2 = &records[1];
+        assert!(part2.examples.is_empty());
+        assert_eq!(None, part2.answer);
+        assert_eq!(Some("part 2 is not solved yet".to_string()), part2.error);
+
+        let line = serde_json::to_value(part2)?;
+        assert_eq!(serde_json::Value::Null, line["answer"]);
+        assert_eq!(2, line["part"]);
+        Ok(())
+    }
+
+    #[test]
+    fn records_errors_without_answers() {
+        let params = &Params::default();
+        let records = run_day(&Lines, &[Part::One], Some(&text("!\n")), params, None);
+        assert_eq!(Some("bad line".to_string()), records[0].error);
+        assert_eq!((None, None), (records[0].parse_ns, records[0].solve_ns));
+
+        let records = run_day(&Lines, &[Part::One], Some(&text("?\n")), params, None);
+        assert_eq!(
+            Some("panicked: unreadable line".to_string()),
+            records[0].error
+        );
+        assert_eq!(None, records[0].parse_ns);
+
+        let records = run_day(&Lines, &[Part::One], None, params, None);
+        assert_eq!(None, records[0].error);
+        assert_eq!(None, records[0].input_sha256);
+        assert_eq!(2, records[0].examples.len());
+    }
+}
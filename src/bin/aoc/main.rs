@@ -0,0 +1,480 @@
+mod bench;
+mod check;
+mod cli;
+mod isolate;
+mod json;
+mod scaffold;
+
+use adv_code_2025::*;
+use anyhow::*;
+use cli::{Command, Format, Selection};
+use isolate::{isolated, Interrupted};
+use rayon::prelude::*;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Pixels per cell side in rendered images.
+const RENDER_SCALE: usize = 4;
+
+/// Runs `f`, also returning how long it took.
+fn timed<T>(f: impl FnOnce() -> Result<T>) -> Result<(T, Duration)> {
+    let start = Instant::now();
+    let result = f()?;
+    Ok((result, start.elapsed()))
+}
+
+/// `error` with its causes, or a diagnostic pointing at the bad token when the input is malformed.
+fn describe(error: &Error) -> String {
+    match error.downcast_ref::<InputError>() {
+        Some(input_error) => input_error.diagnostic(),
+        None => format!("{:#}", error),
+    }
+}
+
+/// The declared parameters of `solution`, with the `--param` overrides it declares applied.
+fn resolve_params(solution: &dyn Puzzle, overrides: &[(String, String)]) -> Result<Params> {
+    let mut params = Params::new(&solution.params());
+    for (name, value) in overrides {
+        if params.is_declared(name) {
+            params.set_str(name, value)?;
+        }
+    }
+    Ok(params)
+}
+
+/// How one part went, for the summary table of `aoc all`.
+struct Row {
+    day: u8,
+    part: Part,
+    /// `ok`, `error`, `panic` or `timeout`.
+    status: &'static str,
+    elapsed: Option<Duration>,
+}
+
+/// What running one day printed, and how each of its parts went.
+struct DayReport {
+    output: String,
+    rows: Vec<Row>,
+}
+
+fn status_of(error: &Error) -> &'static str {
+    match error.downcast_ref::<Interrupted>() {
+        Some(Interrupted::Panicked(_)) => "panic",
+        Some(Interrupted::TimedOut(_)) => "timeout",
+        None => "error",
+    }
+}
+
+/// Runs the requested parts of one day, each on its own thread so that a panic or a timeout only fails that part.
+fn run_day(
+    solution: &'static dyn Puzzle,
+    parts: &[Part],
+    provider: &InputProvider,
+    params: &Params,
+    timeout: Option<Duration>,
+) -> DayReport {
+    let day = solution.day();
+    let mut lines = vec![day_title(&day.to_string())];
+    if params.iter().next().is_some() {
+        lines.push(format!("Params: {}", params));
+    }
+
+    let failed = |lines: Vec<String>, status| DayReport {
+        output: lines.join("\n"),
+        rows: parts
+            .iter()
+            .map(|&part| Row {
+                day,
+                part,
+                status,
+                elapsed: None,
+            })
+            .collect(),
+    };
+
+    let input = match provider.load(day) {
+        Result::Ok(input) => input,
+        Err(e) => {
+            lines.push(format!("Error: {:#}", e));
+            return failed(lines, "error");
+        }
+    };
+
+    // Both parts share the parsed input
+    let parsed = match isolated(timeout, move || timed(|| solution.parse(&input))) {
+        Result::Ok((parsed, elapsed)) => {
+            lines.push(format!("Parsed in {:.2?}", elapsed));
+            Arc::new(parsed)
+        }
+        Err(e) => {
+            lines.push(format!("Error: {}", describe(&e)));
+            return failed(lines, status_of(&e));
+        }
+    };
+
+    let mut rows = Vec::new();
+    for &part in parts {
+        lines.push(format!("\n=== Part {} ===", part));
+
+        let (parsed, params) = (Arc::clone(&parsed), params.clone());
+        match isolated(timeout, move || {
+            timed(|| solution.solve_parsed(part, &parsed, &params))
+        }) {
+            Result::Ok((result, elapsed)) => {
+                if result.is_multiline() {
+                    lines.push(format!("Result =\n{}", result));
+                } else {
+                    lines.push(format!("Result = {}", result));
+                }
+                lines.push(format!("Elapsed: {:.2?}", elapsed));
+                rows.push(Row {
+                    day,
+                    part,
+                    status: "ok",
+                    elapsed: Some(elapsed),
+                });
+            }
+            Err(e) => {
+                lines.push(format!("Error: {}", describe(&e)));
+                rows.push(Row {
+                    day,
+                    part,
+                    status: status_of(&e),
+                    elapsed: None,
+                });
+            }
+        }
+    }
+
+    DayReport {
+        output: lines.join("\n"),
+        rows,
+    }
+}
+
+fn print_summary(rows: &[Row], elapsed: Duration) {
+    println!(
+        "\n{:<3}  {:<4}  {:<8}  {:>10}",
+        "Day", "Part", "Status", "Time"
+    );
+    for row in rows {
+        let time = match row.elapsed {
+            Some(elapsed) => format!("{:.2?}", elapsed),
+            None => "-".to_string(),
+        };
+        println!(
+            "{:02}   {:<4}  {:<8}  {:>10}",
+            row.day,
+            row.part.number(),
+            row.status,
+            time
+        );
+    }
+
+    let ok = rows.iter().filter(|row| row.status == "ok").count();
+    println!(
+        "{} part(s) ok, {} failed, in {:.2?}",
+        ok,
+        rows.len() - ok,
+        elapsed
+    );
+}
+
+/// Benchmarks the requested parts of one day on its real input, returning how many of them failed.
+fn bench_day(
+    solution: &dyn Puzzle,
+    parts: &[Part],
+    provider: &InputProvider,
+    params: &Params,
+    options: bench::Options,
+    history: &mut bench::History,
+) -> usize {
+    let day = solution.day();
+    let input = match provider.load(day) {
+        Result::Ok(input) => input,
+        Err(e) => {
+            println!("Day {:02}  {:#}", day, e);
+            return parts.len();
+        }
+    };
+
+    let mut failures = 0;
+    for &part in parts {
+        print!("Day {:02} part {}  ", day, part);
+        match bench::bench_part(solution, part, &input, params, options) {
+            Result::Ok(record) => {
+                let parse = match record.parse {
+                    Some(parse) => format!("{:.2?}", Duration::from_nanos(parse.median)),
+                    None => "-".to_string(),
+                };
+                let change = match history.previous(&record) {
+                    Some(previous) => format!(
+                        "  ({} vs previous)",
+                        bench::median_change(previous, &record)
+                    ),
+                    None => String::new(),
+                };
+                println!("parse {}  solve {}{}", parse, record.solve, change);
+                history.push(record);
+            }
+            Err(e) => {
+                println!("Error: {}", describe(&e));
+                failures += 1;
+            }
+        }
+    }
+
+    failures
+}
+
+/// Runs the requested parts of one day on its examples, returning how many of them failed.
+fn run_examples(solution: &'static dyn Puzzle, parts: &[Part], timeout: Option<Duration>) -> usize {
+    start_day(&solution.day().to_string());
+
+    let examples = solution.examples();
+    let mut failures = 0;
+    for (i, &part) in parts.iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+        println!("=== Part {} ===", part);
+
+        for example in examples.iter().filter(|e| e.expected(part).is_some()) {
+            let status = check::verify_example(solution, example, part, timeout);
+            println!("Example '{}': {}", example.name, status);
+            if matches!(status, check::Status::Fail(_)) {
+                failures += 1;
+            }
+        }
+    }
+
+    failures
+}
+
+/// Writes the scenes recorded by the `--render` days under `--render-dir`.
+fn export_renders(args: &cli::Args) -> Result<()> {
+    for &day in &args.render {
+        for scene in render::take(day) {
+            let dir = std::path::Path::new(&args.render_dir)
+                .join(format!("day{:02}", day))
+                .join(&scene.name);
+            let files = scene.export(&dir, args.render_format, RENDER_SCALE)?;
+            // Standard error keeps the JSON output parseable
+            eprintln!(
+                "Rendered {} frame(s) of day {:02} '{}' to {}",
+                scene.frames.len(),
+                day,
+                scene.name,
+                match files.as_slice() {
+                    [file] => file.display(),
+                    _ => dir.display(),
+                }
+            );
+        }
+    }
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    let args = match cli::parse_args(std::env::args().skip(1)) {
+        Result::Ok(args) => args,
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, cli::USAGE);
+            std::process::exit(2);
+        }
+    };
+
+    if args.verbose {
+        log::set_level(log::Level::Debug);
+    }
+    for &day in &args.trace {
+        log::set_day_level(day, log::Level::Trace);
+    }
+    for &day in &args.render {
+        render::enable(day);
+    }
+
+    if let (Command::New, Selection::Day(day)) = (args.command, args.selection) {
+        for path in scaffold::new_day(std::path::Path::new(env!("CARGO_MANIFEST_DIR")), day)? {
+            println!("Wrote {}", path.display());
+        }
+        return Ok(());
+    }
+
+    let solutions = match args.selection {
+        Selection::All => days::all(),
+        Selection::Day(day) => {
+            vec![days::get(day).ok_or_else(|| anyhow!("day {} is not registered", day))?]
+        }
+    };
+
+    let provider = match args.input.as_deref() {
+        Some("-") => InputProvider::new().with_source(Stdin::default()),
+        Some(dir) => InputProvider::new().with_source(LocalDir::new(dir)),
+        None => InputProvider::from_env(),
+    };
+
+    // With several days selected, each one only takes the parameters it declares
+    for (name, _) in &args.params {
+        let declared = solutions
+            .iter()
+            .flat_map(|s| s.params())
+            .map(|p| p.name)
+            .collect::<Vec<_>>();
+        if !declared.contains(&name.as_str()) {
+            bail!(
+                "unknown parameter '{}', the selected days declare: {}",
+                name,
+                if declared.is_empty() {
+                    "none".to_string()
+                } else {
+                    declared.join(", ")
+                }
+            );
+        }
+    }
+
+    let parts = args.parts();
+    if args.command == Command::Check {
+        let mut summary = check::Summary::default();
+        for solution in solutions {
+            let params = resolve_params(solution, &args.params)?;
+            check::check_day(solution, &parts, &provider, &params, &mut summary)?;
+        }
+
+        println!("\n{}", summary);
+        if summary.failed > 0 {
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    if args.command == Command::Bench {
+        let options = bench::Options {
+            warmup: args.warmup,
+            iterations: args.iterations,
+        };
+        let mut history = bench::History::load(bench::HISTORY_FILE)?;
+        let mut failures = 0;
+        for solution in solutions {
+            let params = resolve_params(solution, &args.params)?;
+            failures += bench_day(solution, &parts, &provider, &params, options, &mut history);
+        }
+
+        history.save()?;
+        println!("\nResults appended to {}", bench::HISTORY_FILE);
+        if failures > 0 {
+            eprintln!("{} part(s) failed", failures);
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    let solutions = solutions
+        .into_iter()
+        .map(|solution| Ok((solution, resolve_params(solution, &args.params)?)))
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut failures = 0;
+    if args.format == Format::Json {
+        // Without --examples, the real input is run after the examples
+        let provider = (!args.examples).then_some(&provider);
+        let records = solutions
+            .par_iter()
+            .map(|(solution, params)| {
+                json::run_day(*solution, &parts, provider, params, args.timeout)
+            })
+            .collect::<Vec<_>>();
+        for record in records.into_iter().flatten() {
+            println!("{}", serde_json::to_string(&record)?);
+            failures += record.is_failure() as usize;
+        }
+    } else if args.examples {
+        for (i, (solution, _)) in solutions.iter().enumerate() {
+            if i > 0 {
+                println!();
+            }
+            failures += run_examples(*solution, &parts, args.timeout);
+        }
+    } else {
+        // Days run concurrently, but each one's output is printed in order once it is done
+        let start = Instant::now();
+        let reports = solutions
+            .par_iter()
+            .map(|(solution, params)| run_day(*solution, &parts, &provider, params, args.timeout))
+            .collect::<Vec<_>>();
+        for (i, report) in reports.iter().enumerate() {
+            if i > 0 {
+                println!();
+            }
+            println!("{}", report.output);
+        }
+
+        let rows = reports.into_iter().flat_map(|r| r.rows).collect::<Vec<_>>();
+        if solutions.len() > 1 {
+            print_summary(&rows, start.elapsed());
+        }
+        failures = rows.iter().filter(|row| row.status != "ok").count();
+    }
+
+    export_renders(&args)?;
+
+    if failures > 0 {
+        eprintln!("{} part(s) failed", failures);
+        std::process::exit(1);
+    }
+
+    Ok(())
+}
+
+/// A day for the runner's tests, whose answer is its number of lines.
+#[cfg(test)]
+mod fixtures {
+    use adv_code_2025::*;
+    use anyhow::*;
+
+    pub struct Lines;
+
+    impl Solution for Lines {
+        type Parsed = usize;
+
+        fn day(&self) -> u8 {
+            0
+        }
+
+        fn examples(&self) -> Vec<Example> {
+            vec![
+                Example::new("ok", "a\n").part1(1),
+                Example::new("wrong", "a\nb\n").part1(3),
+            ]
+        }
+
+        /// Fails on a `!` and panics on a `?`.
+        fn parse(&self, input: &str) -> Result<usize> {
+            ensure!(!input.contains('!'), "bad line");
+            assert!(!input.contains('?'), "unreadable line");
+            Ok(input.lines().count())
+        }
+
+        fn part1(&self, lines: &usize, _params: &Params) -> Result<Answer> {
+            Ok((*lines).into())
+        }
+    }
+
+    struct Text(&'static str);
+
+    impl InputSource for Text {
+        fn describe(&self, _day: u8) -> String {
+            "text".to_string()
+        }
+
+        fn load(&self, _day: u8) -> Result<Option<String>> {
+            Ok(Some(self.0.to_string()))
+        }
+    }
+
+    /// Provides `text` as the input of every day.
+    pub fn text(text: &'static str) -> InputProvider {
+        InputProvider::new().with_source(Text(text))
+    }
+}
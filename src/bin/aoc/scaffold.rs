@@ -0,0 +1,192 @@
+use anyhow::*;
+use regex::Regex;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const TEMPLATE: &str = include_str!("../../../template/NN.rs");
+
+/// Fills the day number in `template/NN.rs`.
+pub fn render(day: u8) -> Result<String> {
+    let replacements = [
+        (
+            "DayNN; // TODO: Rename with the day number",
+            format!("Day{:02};", day),
+        ),
+        ("DayNN", format!("Day{:02}", day)),
+        ("0 // TODO: Fill the day", day.to_string()),
+    ];
+
+    let mut text = TEMPLATE.to_string();
+    for (placeholder, value) in replacements {
+        ensure!(
+            text.contains(placeholder),
+            "template/NN.rs no longer contains '{}'",
+            placeholder
+        );
+        text = text.replace(placeholder, &value);
+    }
+    Ok(text)
+}
+
+/// Inserts `line` among the lines of `text` matching `pattern`, keeping them sorted by day.
+///
+/// `pattern` captures the day number of a line in its first group.
+fn insert_sorted(text: &str, pattern: &str, day: u8, line: &str) -> Result<String> {
+    let pattern = Regex::new(pattern)?;
+    let lines = text.lines().collect::<Vec<_>>();
+    let matching = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, l)| Some((i, pattern.captures(l)?[1].parse::<u8>().ok()?)))
+        .collect::<Vec<_>>();
+    let &(last, _) = matching
+        .last()
+        .ok_or_else(|| anyhow!("no line matches '{}'", pattern))?;
+    ensure!(
+        matching.iter().all(|&(_, d)| d != day),
+        "day {} is already registered",
+        day
+    );
+
+    let position = matching
+        .iter()
+        .find(|&&(_, d)| d > day)
+        .map_or(last + 1, |&(i, _)| i);
+    let indent = &lines[last][..lines[last].len() - lines[last].trim_start().len()];
+
+    let mut result = lines[..position].to_vec();
+    let line = format!("{}{}", indent, line);
+    result.push(&line);
+    result.extend(&lines[position..]);
+    Ok(result.join("\n") + "\n")
+}
+
+/// Declares the module of `day` in `src/days/mod.rs` and adds it to `all()`.
+pub fn register_module(mod_rs: &str, day: u8) -> Result<String> {
+    let text = insert_sorted(
+        mod_rs,
+        r"^pub mod day(\d+);$",
+        day,
+        &format!("pub mod day{:02};", day),
+    )?;
+    insert_sorted(
+        &text,
+        r"^\s*&day(\d+)::Day\d+,$",
+        day,
+        &format!("&day{0:02}::Day{0:02},", day),
+    )
+}
+
+/// Adds `day` to the `regression!` list of `tests/regression.rs`.
+pub fn register_regression(regression_rs: &str, day: u8) -> Result<String> {
+    insert_sorted(
+        regression_rs,
+        r"^\s*day(\d+) => \d+,$",
+        day,
+        &format!("day{:02} => {},", day, day),
+    )
+}
+
+/// Creates the module, input placeholder and answers of a new day in the repository at `root`.
+///
+/// Returns the created files. Nothing is written when the day already exists.
+pub fn new_day(root: &Path, day: u8) -> Result<Vec<PathBuf>> {
+    ensure!(
+        (1..=25).contains(&day),
+        "day {} is not between 1 and 25",
+        day
+    );
+
+    let module = root.join(format!("src/days/day{:02}.rs", day));
+    ensure!(
+        !module.exists(),
+        "{} already exists, not overwriting it",
+        module.display()
+    );
+
+    // Everything is prepared before writing, so that a failure leaves the tree untouched
+    let mod_rs = root.join("src/days/mod.rs");
+    let regression_rs = root.join("tests/regression.rs");
+    let mut files = vec![
+        (module, render(day)?),
+        (
+            mod_rs.clone(),
+            register_module(&fs::read_to_string(&mod_rs)?, day)?,
+        ),
+        (
+            regression_rs.clone(),
+            register_regression(&fs::read_to_string(&regression_rs)?, day)?,
+        ),
+    ];
+
+    // An existing input or answers file is kept as is
+    let input = root.join(format!("input/{:02}.txt", day));
+    if !input.exists() {
+        files.push((input, String::new()));
+    }
+    let answers = root.join(format!("answers/{:02}.toml", day));
+    if !answers.exists() {
+        files.push((answers, "[real]\n".to_string()));
+    }
+
+    for (path, text) in &files {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, text).with_context(|| format!("failed to write {}", path.display()))?;
+    }
+
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MOD_RS: &str = "\
+pub mod day01;
+pub mod day03;
+
+pub fn all() -> Vec<&'static dyn Puzzle> {
+    vec![
+        &day01::Day01,
+        &day03::Day03,
+    ]
+}
+";
+
+    #[test]
+    fn renders_the_template() -> Result<()> {
+        let text = render(13)?;
+        assert!(text.contains("pub struct Day13;\n"));
+        assert!(text.contains("impl Solution for Day13 {"));
+        assert!(text.contains("        13\n"));
+        assert!(!text.contains("NN"));
+        Ok(())
+    }
+
+    #[test]
+    fn registers_modules_in_order() -> Result<()> {
+        let text = register_module(MOD_RS, 2)?;
+        assert!(text.starts_with("pub mod day01;\npub mod day02;\npub mod day03;\n"));
+        assert!(text
+            .contains("        &day01::Day01,\n        &day02::Day02,\n        &day03::Day03,\n"));
+
+        let text = register_module(MOD_RS, 13)?;
+        assert!(text.contains("pub mod day03;\npub mod day13;\n"));
+        assert!(text.contains("        &day03::Day03,\n        &day13::Day13,\n    ]"));
+
+        assert!(register_module(MOD_RS, 3).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn registers_regression_tests() -> Result<()> {
+        let text = register_regression("regression! {\n    day01 => 1,\n}\n", 13)?;
+        assert_eq!(
+            "regression! {\n    day01 => 1,\n    day13 => 13,\n}\n",
+            text
+        );
+        Ok(())
+    }
+}
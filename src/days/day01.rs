@@ -0,0 +1,124 @@
+use crate::dial::{self, Counting, Dial, Instruction, Lock};
+use crate::parse;
+use crate::{trace, Answer, Example, Param, Params, Solution};
+use anyhow::*;
+use itertools::Itertools;
+
+const TEST: &str = "\
+L68
+L30
+R48
+L5
+R60
+L55
+L1
+L99
+R14
+L82
+";
+
+const TEST_LOCK: &str = "\
+L68
+2:R15
+3:L30
+1:R18
+2:L5
+";
+
+/// The lock described by the `dials`, `size`, `start`, `targets` and `gears` parameters.
+fn lock(params: &Params, counting: Counting) -> Result<Lock> {
+    let targets = params
+        .get::<String>("targets")?
+        .split(',')
+        .map(|target| {
+            target
+                .trim()
+                .parse::<i64>()
+                .map_err(|_| anyhow!("invalid target '{}'", target))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let dial = Dial::new(params.get("size")?, params.get("start")?)?
+        .targets(targets)
+        .counting(counting);
+
+    let mut lock = Lock::new(vec![dial; params.get("dials")?])?;
+    for gear in params.get::<String>("gears")?.split(',') {
+        if !gear.trim().is_empty() {
+            lock = lock.gear(gear.parse()?)?;
+        }
+    }
+    Ok(lock)
+}
+
+/// Turns `lock` by each instruction, answering with its count, or the count of each dial and the combination.
+fn open(mut lock: Lock, instructions: &[Instruction]) -> Result<Answer> {
+    for &instruction in instructions {
+        lock.turn(instruction)?;
+        trace!("{} dials: {:?}", instruction, lock.combination());
+    }
+
+    if let [dial] = lock.dials() {
+        return Ok(dial.total().into());
+    }
+    Ok(format!(
+        "zeros {}; combination {}",
+        lock.dials().iter().map(Dial::total).join(","),
+        lock.combination().iter().join("-")
+    )
+    .into())
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Parsed = Vec<Instruction>;
+
+    fn day(&self) -> u8 {
+        1
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new("main", TEST).part1(3).part2(6),
+            Example::new("lock", TEST_LOCK)
+                .param("dials", 3)
+                .param("gears", "1>2*2, 2>3*-1")
+                .part1("zeros 1,0,0; combination 0-60-10")
+                .part2("zeros 2,1,1; combination 0-60-10"),
+        ]
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new(
+                "start",
+                50,
+                "Position the dial points at before the first rotation",
+            ),
+            Param::new("size", 100, "Number of positions on the dial"),
+            Param::new("dials", 1, "Number of dials on the lock"),
+            Param::new(
+                "gears",
+                "",
+                "Comma-separated gears such as 1>2*3: each click of dial 1 turns dial 2 by 3",
+            ),
+            Param::new(
+                "targets",
+                "0",
+                "Comma-separated positions whose hits are counted",
+            ),
+        ]
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        parse::lines(input, dial::instruction)
+    }
+
+    fn part1(&self, instructions: &Self::Parsed, params: &Params) -> Result<Answer> {
+        open(lock(params, Counting::Land)?, instructions)
+    }
+
+    fn part2(&self, instructions: &Self::Parsed, params: &Params) -> Result<Answer> {
+        open(lock(params, Counting::Both)?, instructions)
+    }
+}
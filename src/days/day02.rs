@@ -0,0 +1,58 @@
+use crate::digits::{Pattern, Repeated};
+use crate::parse;
+use crate::{Answer, Example, Param, Params, Solution};
+use anyhow::*;
+use std::ops::RangeInclusive;
+
+const TEST: &str = "\
+11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124
+";
+
+fn base(params: &Params) -> Result<u32> {
+    let base = params.get("base")?;
+    ensure!(
+        (2..=36).contains(&base),
+        "base {} is not between 2 and 36",
+        base
+    );
+    Ok(base)
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    /// The ranges of IDs, such as `11-22,95-115`.
+    type Parsed = Vec<RangeInclusive<u64>>;
+
+    fn day(&self) -> u8 {
+        2
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new("main", TEST)
+            .part1(1227775554)
+            .part2(4174379265_u64)]
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![Param::new(
+            "base",
+            10,
+            "Base the repeated digits of the IDs are looked for in; ranges stay decimal",
+        )]
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        let lines = parse::lines(input, parse::separated(parse::range, ","))?;
+        Ok(lines.into_iter().flatten().collect())
+    }
+
+    fn part1(&self, ranges: &Self::Parsed, params: &Params) -> Result<Answer> {
+        // An invalid ID is a block repeated twice, of any length
+        Ok(Repeated::exactly(2).sum(ranges, base(params)?).into())
+    }
+
+    fn part2(&self, ranges: &Self::Parsed, params: &Params) -> Result<Answer> {
+        Ok(Repeated::at_least(2).sum(ranges, base(params)?).into())
+    }
+}
@@ -0,0 +1,119 @@
+use crate::parse::{self, Cursor};
+use crate::{trace, Answer, Example, Param, Params, Solution};
+use anyhow::*;
+use itertools::Itertools;
+
+const TEST: &str = "\
+987654321111111
+811111111111119
+234234234234278
+818181911112111
+";
+
+/// The joltage rating of every battery of a bank, one digit each.
+fn bank(cursor: &mut Cursor) -> Result<Vec<usize>> {
+    let digits = cursor.take_while(|c| c.is_ascii_digit());
+    cursor.end()?;
+    if digits.len() < 2 {
+        return Err(cursor.error("expected a bank of at least 2 batteries"));
+    }
+    Ok(digits.bytes().map(|b| (b - b'0') as usize).collect())
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Parsed = Vec<Vec<usize>>;
+
+    fn day(&self) -> u8 {
+        3
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new("main", TEST)
+                .part1(357)
+                .part2(3121910778619_u64),
+            // Two joltages of 19 digits add up past 64 bits
+            Example::new("long", "9999999999999999999\n9999999999999999999\n")
+                .part1(198)
+                .part2(19_999_999_999_999_999_998_u128)
+                .param("digits", 19),
+        ]
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![Param::new(
+            "digits",
+            12,
+            "Number of batteries turned on per bank in part 2",
+        )]
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        parse::lines(input, bank)
+    }
+
+    fn part1(&self, banks: &Self::Parsed, _params: &Params) -> Result<Answer> {
+        let mut result = 0;
+
+        for numbers in banks {
+            let first_max = numbers[..numbers.len() - 1].iter().max().unwrap();
+            let first_position = numbers.iter().position(|f| f == first_max).unwrap();
+            let second_max = numbers[first_position + 1..].iter().max().unwrap();
+
+            let concatenated = format!("{}{}", first_max, second_max).parse::<usize>()?;
+
+            result += concatenated;
+        }
+
+        Ok(result.into())
+    }
+
+    fn part2(&self, banks: &Self::Parsed, params: &Params) -> Result<Answer> {
+        let num_to_have = params.get::<usize>("digits")?;
+        ensure!(num_to_have > 0, "digits must be at least 1");
+        let mut result = 0u128;
+
+        for numbers in banks {
+            ensure!(
+                numbers.len() >= num_to_have,
+                "a bank of {} batteries can't turn on {}",
+                numbers.len(),
+                num_to_have
+            );
+
+            let mut joltage = 0u128;
+            let mut start_position = 0;
+
+            for i in 0..num_to_have {
+                let end_index = numbers.len() - num_to_have + i;
+
+                let max_value = numbers[start_position..=end_index]
+                    .iter()
+                    .max()
+                    .copied()
+                    .unwrap();
+
+                joltage = joltage
+                    .checked_mul(10)
+                    .and_then(|joltage| joltage.checked_add(max_value as u128))
+                    .ok_or_else(|| anyhow!("{} digits do not fit in 128 bits", num_to_have))?;
+
+                start_position = numbers[start_position..=end_index]
+                    .iter()
+                    .position(|&f| f == max_value)
+                    .unwrap()
+                    + start_position
+                    + 1;
+            }
+
+            result = result
+                .checked_add(joltage)
+                .ok_or_else(|| anyhow!("the total joltage does not fit in 128 bits"))?;
+            trace!("{} -> {} = {}", numbers.iter().join(""), joltage, result);
+        }
+
+        Ok(result.into())
+    }
+}
@@ -0,0 +1,80 @@
+use crate::{snapshot, trace, Answer, Example, Grid, Params, Position, Solution};
+use anyhow::*;
+use itertools::Itertools;
+
+const TEST: &str = "\
+..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.
+";
+
+/// Whether the roll at `position` has fewer than 4 rolls around it.
+fn is_accessible(grid: &Grid<char>, position: Position) -> bool {
+    grid.neighbors8(position)
+        .filter(|&neighbor| grid[neighbor] == '@')
+        .count()
+        < 4
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Parsed = Grid<char>;
+
+    fn day(&self) -> u8 {
+        4
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new("main", TEST).part1(13).part2(43)]
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        input.parse()
+    }
+
+    fn part1(&self, grid: &Self::Parsed, _params: &Params) -> Result<Answer> {
+        let result = grid
+            .find_all(&'@')
+            .filter(|&position| is_accessible(grid, position))
+            .count();
+
+        Ok(result.into())
+    }
+
+    fn part2(&self, grid: &Self::Parsed, _params: &Params) -> Result<Answer> {
+        let mut grid = grid.clone();
+
+        let mut result = 0;
+        snapshot!("removal", grid);
+
+        loop {
+            let to_remove = grid
+                .find_all(&'@')
+                .filter(|&position| is_accessible(&grid, position))
+                .collect_vec();
+
+            let iteration_result = to_remove.len();
+            result += iteration_result;
+            if iteration_result == 0 {
+                break;
+            }
+
+            for position in to_remove {
+                grid[position] = 'x';
+            }
+
+            trace!("removed {} rolls\n{}", iteration_result, grid);
+            snapshot!("removal", grid);
+        }
+
+        Ok(result.into())
+    }
+}
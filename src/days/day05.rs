@@ -0,0 +1,65 @@
+use crate::parse;
+use crate::{trace, Answer, Example, IntervalSet, Params, Solution};
+use anyhow::*;
+
+const TEST: &str = "\
+3-5
+10-14
+16-20
+12-18
+
+1
+5
+8
+11
+17
+32
+";
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    /// The fresh ingredient ranges and the ingredients to check.
+    type Parsed = (IntervalSet<u64>, Vec<u64>);
+
+    fn day(&self) -> u8 {
+        5
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new("main", TEST).part1(3).part2(14)]
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        let blocks = parse::blocks(input);
+        let [fresh_ranges, to_check] = blocks.as_slice() else {
+            bail!("expected ranges and ingredients separated by an empty line");
+        };
+
+        Ok((
+            fresh_ranges
+                .parse_lines(parse::range)?
+                .into_iter()
+                .collect(),
+            to_check.parse_lines(parse::int)?,
+        ))
+    }
+
+    fn part1(&self, (fresh_ranges, to_check): &Self::Parsed, _params: &Params) -> Result<Answer> {
+        let mut result = 0;
+        for &number in to_check {
+            if fresh_ranges.contains(number) {
+                result += 1;
+                trace!("{} is fresh", number);
+            }
+        }
+
+        Ok(result.into())
+    }
+
+    fn part2(&self, (fresh_ranges, _): &Self::Parsed, _params: &Params) -> Result<Answer> {
+        trace!("merged ranges: {}", fresh_ranges);
+
+        Ok(fresh_ranges.len().into())
+    }
+}
@@ -0,0 +1,171 @@
+use crate::parse;
+use crate::{Answer, Example, Grid, InputError, Params, Solution};
+use anyhow::*;
+use itertools::Itertools;
+
+const TEST: &str = "\
+123 328  51 64
+ 45 64  387 23
+  6 98  215 314
+*   +   *   +
+";
+
+/// The worksheet, read both as rows of numbers and as a grid of characters.
+pub struct Worksheet {
+    numbers: Grid<usize>,
+    operators: Vec<char>,
+    cells: Grid<char>,
+    /// Line number of the operators, the last line.
+    operators_line: usize,
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Parsed = Worksheet;
+
+    fn day(&self) -> u8 {
+        6
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new("main", TEST).part1(4277556).part2(3263827)]
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        let lines = input.lines().collect_vec();
+        let Some((operators, numbers)) = lines.split_last() else {
+            return Err(InputError::new(1, 1, "", "empty worksheet").into());
+        };
+
+        // Every row needs as many numbers as the first one, and as many operators
+        let mut rows: Vec<Vec<usize>> = Vec::new();
+        for (i, line) in numbers.iter().enumerate() {
+            let width = rows.first().map(Vec::len);
+            rows.push(parse::line(i + 1, line, |cursor| {
+                let row = parse::spaced(parse::int)(cursor)?;
+                match width {
+                    Some(width) if row.len() != width => Err(cursor.error(format!(
+                        "expected {} numbers, found {}",
+                        width,
+                        row.len()
+                    ))),
+                    _ => Ok(row),
+                }
+            })?);
+        }
+        let numbers = Grid::from_rows(rows)?;
+        let operators = parse::line(lines.len(), operators, |cursor| {
+            let operators = parse::spaced(|cursor| cursor.one_of(&['*', '+']))(cursor)?;
+            if operators.len() != numbers.width() {
+                return Err(cursor.error(format!(
+                    "expected {} operators, found {}",
+                    numbers.width(),
+                    operators.len()
+                )));
+            }
+            Ok(operators)
+        })?;
+
+        Ok(Worksheet {
+            numbers,
+            operators,
+            cells: Grid::parse_padded(&mut input.as_bytes(), ' ')?,
+            operators_line: lines.len(),
+        })
+    }
+
+    fn part1(&self, worksheet: &Self::Parsed, _params: &Params) -> Result<Answer> {
+        let mut result = 0;
+
+        for (line, operation) in worksheet.numbers.columns().zip(&worksheet.operators) {
+            let line_result = line
+                .copied()
+                .reduce(|a, b| match operation {
+                    '*' => a * b,
+                    _ => a + b,
+                })
+                .unwrap_or(0);
+
+            result += line_result;
+        }
+
+        Ok(result.into())
+    }
+
+    fn part2(&self, worksheet: &Self::Parsed, _params: &Params) -> Result<Answer> {
+        let grid = &worksheet.cells;
+        let (width, height) = (grid.width(), grid.height());
+
+        // Find separators columns
+        let is_sep = grid
+            .columns()
+            .map(|mut column| column.all(|c| c.is_whitespace()))
+            .collect_vec();
+
+        let mut result = 0;
+        let mut x = 0;
+        while x < width {
+            // Skip separator columns
+            while x < width && is_sep[x] {
+                x += 1;
+            }
+
+            if x >= width {
+                break;
+            }
+
+            let col_start = x;
+            while x < width && !is_sep[x] {
+                x += 1;
+            }
+
+            let col_end = x;
+
+            // Get operator
+            let mut op = None;
+            for &c in &grid.row(height - 1)[col_start..col_end] {
+                if c == '*' || c == '+' {
+                    op = Some(c);
+                    break;
+                }
+            }
+
+            let Some(op) = op else {
+                return Err(InputError::new(
+                    worksheet.operators_line,
+                    col_start + 1,
+                    grid.row(height - 1).iter().collect::<String>().trim_end(),
+                    "no operator for this column",
+                )
+                .into());
+            };
+
+            // Collect numbers
+            let mut nums = Vec::new();
+            for cx in col_start..col_end {
+                let mut digits = String::new();
+
+                for &c in grid.column(cx).take(height - 1) {
+                    if c.is_ascii_digit() {
+                        digits.push(c);
+                    }
+                }
+
+                if !digits.is_empty() {
+                    nums.push(digits.parse::<usize>()?);
+                }
+            }
+
+            let col_value = match op {
+                '*' => nums.into_iter().product::<usize>(),
+                '+' => nums.into_iter().sum(),
+                _ => unreachable!(),
+            };
+
+            result += col_value;
+        }
+
+        Ok(result.into())
+    }
+}
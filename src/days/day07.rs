@@ -0,0 +1,167 @@
+use crate::render::overlay;
+use crate::{snapshot, Answer, Example, Grid, InputError, Params, Position, Solution};
+use anyhow::*;
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+
+const TEST: &str = "\
+.......S.......
+...............
+.......^.......
+...............
+......^.^......
+...............
+.....^.^.^.....
+...............
+....^.^...^....
+...............
+...^.^...^.^...
+...............
+..^...^.....^..
+...............
+.^.^.^.^.^...^.
+...............
+";
+
+/// Where the beam enters the manifold, or an error pointing at its first row.
+fn start(grid: &Grid<char>) -> Result<Position> {
+    grid.find(&'S').ok_or_else(|| {
+        let first_row = grid.rows().next().unwrap_or_default();
+        InputError::new(
+            1,
+            1,
+            first_row.iter().collect::<String>(),
+            "expected a start position 'S'",
+        )
+        .into()
+    })
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Parsed = Grid<char>;
+
+    fn day(&self) -> u8 {
+        7
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new("main", TEST).part1(21).part2(40)]
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        for (i, line) in input.lines().enumerate() {
+            if let Some((column, c)) = line
+                .chars()
+                .enumerate()
+                .find(|&(_, c)| !matches!(c, '.' | '^' | 'S'))
+            {
+                return Err(InputError::new(
+                    i + 1,
+                    column + 1,
+                    line,
+                    format!("unexpected '{}', expected '.', '^' or 'S'", c),
+                )
+                .into());
+            }
+        }
+        input.parse()
+    }
+
+    fn part1(&self, grid: &Self::Parsed, _params: &Params) -> Result<Answer> {
+        let height = grid.height();
+        let width = grid.width();
+
+        let (start_row, start_column) = start(grid)?;
+        let mut y = start_row + 1; // the beam goes down from the start
+        let mut beams_x_index = HashSet::from([start_column]);
+
+        // Every cell a beam went through, drawn over the manifold as it goes down
+        let mut lit = Vec::new();
+        let mut total = 0;
+        while y < height {
+            snapshot!("beams", {
+                lit.extend(
+                    beams_x_index
+                        .iter()
+                        .map(|&x| (y, x))
+                        .filter(|&position| grid[position] == '.'),
+                );
+                overlay(grid, lit.iter().copied(), '|')
+            });
+
+            let splitters = grid.row(y).iter().positions(|&s| s == '^').collect_vec();
+
+            if splitters.is_empty() {
+                y += 1;
+                continue;
+            }
+
+            for split in splitters {
+                if beams_x_index.contains(&split) {
+                    beams_x_index.remove(&split);
+                    let mut to_add = Vec::new();
+                    if split > 0 {
+                        to_add.push(split - 1);
+                    }
+
+                    if split < width - 1 {
+                        to_add.push(split + 1);
+                    }
+
+                    total += 1;
+
+                    beams_x_index.extend(to_add);
+                }
+            }
+
+            y += 1;
+        }
+
+        Ok(total.into())
+    }
+
+    fn part2(&self, grid: &Self::Parsed, _params: &Params) -> Result<Answer> {
+        let (start_row, start_column) = start(grid)?;
+
+        // The memo is local so that the example and the real input never share cached paths
+        let mut memo = HashMap::new();
+        return Ok(ways(grid, start_row, start_column, &mut memo).into());
+
+        fn ways(
+            grid: &Grid<char>,
+            row: usize,
+            column: usize,
+            memo: &mut HashMap<(usize, usize), usize>,
+        ) -> usize {
+            if row >= grid.height() {
+                return 1;
+            } else if column >= grid.width() {
+                return 0;
+            }
+
+            if let Some(&cached) = memo.get(&(row, column)) {
+                return cached;
+            }
+
+            let result = match grid[(row, column)] {
+                '.' | 'S' => ways(grid, row + 1, column, memo),
+                '^' => {
+                    let mut result = 0;
+                    if column + 1 < grid.width() {
+                        result += ways(grid, row, column + 1, memo);
+                    }
+                    if column > 0 {
+                        result += ways(grid, row, column - 1, memo);
+                    }
+                    result
+                }
+                cell => unreachable!("'{}' is rejected by parse", cell),
+            };
+
+            memo.insert((row, column), result);
+            result
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use crate::parse;
+use crate::{debug, trace, Answer, DisjointSet, Example, Graph, Param, Params, Solution, Vector3};
+use anyhow::*;
+use itertools::Itertools;
+
+const TEST: &str = "\
+162,817,812
+57,618,57
+906,360,560
+592,479,940
+352,342,300
+466,668,158
+542,29,236
+431,825,988
+739,650,466
+52,470,668
+216,146,977
+819,987,18
+117,168,530
+805,96,715
+346,949,466
+970,615,88
+941,993,340
+862,61,35
+984,92,344
+425,690,689
+";
+
+#[derive(Clone, Copy, Debug)]
+pub struct VectorDistance {
+    a: usize,
+    b: usize,
+    distance: u128,
+}
+
+fn get_sorted_distances_and_coords(
+    input: &str,
+) -> Result<(Vec<Vector3<i64>>, Vec<VectorDistance>)> {
+    let coords = parse::lines(input, |cursor| {
+        let coords = parse::separated(parse::int, ",")(cursor)?;
+        match coords[..] {
+            [x, y, z] => Ok(Vector3::new(x, y, z)),
+            _ => Err(cursor.error(format!("expected 3 coordinates, found {}", coords.len()))),
+        }
+    })?;
+
+    trace!("Coords: {:?}", coords);
+
+    let mut sorted_distances = Vec::new();
+    // Each pair once, so that pairs at equal distances keep the order of their boxes
+    for i in 0..coords.len() {
+        for j in i + 1..coords.len() {
+            sorted_distances.push(VectorDistance {
+                a: i,
+                b: j,
+                distance: coords[i].squared_distance(coords[j]),
+            });
+        }
+    }
+
+    sorted_distances.sort_by_key(|d| d.distance);
+
+    Ok((coords, sorted_distances))
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    /// The junction boxes, and every pair of them from the closest to the farthest.
+    type Parsed = (Vec<Vector3<i64>>, Vec<VectorDistance>);
+
+    fn day(&self) -> u8 {
+        8
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new("main", TEST)
+                .part1(40)
+                .part2(25272)
+                .param("connect", 10),
+            // The product of the X coordinates no longer fits in 64 bits
+            Example::new("far", "5000000000,0,0\n7000000000,1,2\n")
+                .part2(35_000_000_000_000_000_000i128),
+        ]
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![Param::new(
+            "connect",
+            1_000,
+            "Number of closest pairs connected in part 1",
+        )]
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        get_sorted_distances_and_coords(input)
+    }
+
+    fn part1(&self, (coords, sorted_distances): &Self::Parsed, params: &Params) -> Result<Answer> {
+        let number_to_connect = params.get::<usize>("connect")?;
+        let mut graph = Graph::undirected();
+        for _ in 0..coords.len() {
+            graph.add_node();
+        }
+
+        for vec_distance in sorted_distances.iter().take(number_to_connect) {
+            trace!("{:?}", vec_distance);
+            graph.add_edge(vec_distance.a, vec_distance.b);
+        }
+
+        let mut all_paths = graph.components().iter().map(Vec::len).collect_vec();
+        all_paths.sort_unstable_by(|a, b| b.cmp(a));
+        let three_largest_paths = all_paths.iter().take(3).copied().collect_vec();
+
+        Ok(three_largest_paths.iter().product::<usize>().into())
+    }
+
+    fn part2(&self, (coords, sorted_distances): &Self::Parsed, _params: &Params) -> Result<Answer> {
+        let mut circuits = DisjointSet::new(coords.len());
+
+        let mut i = 0;
+        while i < sorted_distances.len() {
+            let vec_distance = sorted_distances[i];
+            circuits.union(vec_distance.a, vec_distance.b);
+            if circuits.component_count() == 1 {
+                let (vec1, vec2) = (coords[vec_distance.a], coords[vec_distance.b]);
+                debug!("{} with {}", vec1, vec2);
+                return Ok((i128::from(vec1.x) * i128::from(vec2.x)).into());
+            }
+            i += 1;
+        }
+
+        Err(anyhow!("No solution found"))
+    }
+}
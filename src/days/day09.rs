@@ -0,0 +1,163 @@
+use crate::parse;
+use crate::{
+    debug, snapshot, trace, Answer, BoundingBox, Example, Grid, Params, Solution, Vector2,
+};
+use anyhow::*;
+use itertools::Itertools;
+use rayon::prelude::*;
+
+const TEST: &str = "\
+7,1
+11,1
+11,7
+9,7
+9,5
+2,5
+2,3
+7,3
+";
+
+/// Number of tiles of the rectangle with opposite corners `a` and `b`.
+fn area(a: Vector2<usize>, b: Vector2<usize>) -> u128 {
+    BoundingBox {
+        min: a.min(b),
+        max: a.max(b),
+    }
+    .cells()
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    /// The red tiles, in order around the loop.
+    type Parsed = Vec<Vector2<usize>>;
+
+    fn day(&self) -> u8 {
+        9
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new("main", TEST).part1(50).part2(24)]
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        parse::lines(input, |cursor| {
+            let (x, y) = parse::pair(parse::int, ",", parse::int)(cursor)?;
+            Ok(Vector2::new(x, y))
+        })
+    }
+
+    fn part1(&self, vectors: &Self::Parsed, _params: &Params) -> Result<Answer> {
+        let (i, j, surface) = vectors
+            .iter()
+            .enumerate()
+            .map(|(i, vec)| {
+                vectors
+                    .iter()
+                    .enumerate()
+                    .map(|(j, other)| (i, j, area(*vec, *other)))
+                    .max_by_key(|(_, _, area)| *area)
+                    .unwrap()
+            })
+            .max_by_key(|(_, _, area)| *area)
+            .ok_or_else(|| anyhow!("No red tile"))?;
+
+        debug!("Max at ({}, {}) = {}", vectors[i], vectors[j], surface);
+
+        Ok(surface.into())
+    }
+
+    fn part2(&self, vectors: &Self::Parsed, _params: &Params) -> Result<Answer> {
+        let bounds = BoundingBox::from_points(vectors.iter().copied())
+            .ok_or_else(|| anyhow!("No red tile"))?;
+
+        let delta_x = bounds.max.x - bounds.min.x + 1;
+        let delta_y = bounds.max.y - bounds.min.y + 1;
+
+        let vectors = vectors.iter().map(|&vec| vec - bounds.min).collect_vec();
+
+        // Tiles that are red or green
+        let mut grid = Grid::new(delta_x + 1, delta_y, false);
+
+        for vector in vectors.iter() {
+            grid[(vector.y, vector.x)] = true;
+        }
+
+        // Bounding box
+        for i in 0..vectors.len() {
+            let vec1 = vectors[i];
+            let vec2 = if i == vectors.len() - 1 {
+                vectors[0]
+            } else {
+                vectors[i + 1]
+            };
+
+            // Vertical line
+            if vec1.x == vec2.x {
+                let min = vec1.y.min(vec2.y);
+                let max = vec1.y.max(vec2.y);
+                for y in (min + 1)..max {
+                    grid[(y, vec1.x)] = true;
+                }
+            } else if vec1.y == vec2.y {
+                let min = vec1.x.min(vec2.x);
+                let max = vec1.x.max(vec2.x);
+                for x in (min + 1)..max {
+                    grid[(vec1.y, x)] = true;
+                }
+            }
+        }
+
+        debug!(
+            "Computing intervals for {} lines and {} cols",
+            grid.height(),
+            grid.width()
+        );
+        for y in 0..grid.height() {
+            let row = grid.row(y);
+            if let (Some(first), Some(last)) = (
+                row.iter().position(|&tile| tile),
+                row.iter().rposition(|&tile| tile),
+            ) {
+                for x in first..=last {
+                    grid[(y, x)] = true;
+                }
+            }
+        }
+
+        trace!("{}", grid.map(|&tile| if tile { 'X' } else { '.' }));
+        snapshot!("tiles", grid);
+
+        // This takes 9+ minutes on the input...
+        debug!("Finding best surface");
+        let best_surface = vectors
+            .par_iter()
+            .map(|first_vec| {
+                let mut best_area = 0;
+                for second_vec in vectors.iter().filter(|f| *f != first_vec) {
+                    if first_vec.x == second_vec.x || first_vec.y == second_vec.y {
+                        continue;
+                    }
+
+                    let top = first_vec.y.min(second_vec.y);
+                    let bot = second_vec.y.max(first_vec.y);
+                    let left = second_vec.x.min(first_vec.x);
+                    let right = first_vec.x.max(second_vec.x);
+
+                    let are_all_inside =
+                        (top..=bot).all(|yy| grid.row(yy)[left..=right].iter().all(|&tile| tile));
+
+                    if are_all_inside {
+                        best_area = area(*first_vec, *second_vec).max(best_area);
+                    }
+                }
+                best_area
+            })
+            .max();
+
+        match best_surface {
+            Some(surface) => Ok(surface.into()),
+            None => Err(anyhow!("No surface")),
+        }
+    }
+}
@@ -0,0 +1,214 @@
+use crate::parse::{self, Cursor};
+use crate::{debug, trace, Answer, Example, Params, Solution};
+use anyhow::*;
+use good_lp::Solution as _;
+use good_lp::*;
+use itertools::Itertools;
+
+const TEST: &str = "\
+[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
+[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}
+[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}
+";
+
+fn format_int(value: &u32, size: &usize) -> String {
+    format!("{:0width$b}", value, width = size)
+}
+
+/// A machine line such as `[.##.] (3) (1,3) {3,5,4,7}`.
+#[derive(Debug, Clone)]
+pub struct Machine {
+    /// The indicator lights diagram, made of `.` and `#`.
+    lights: String,
+    /// Indices of the counters each button touches.
+    buttons: Vec<Vec<usize>>,
+    joltages: Vec<u32>,
+}
+
+fn machine(cursor: &mut Cursor) -> Result<Machine> {
+    cursor.literal("[")?;
+    let before = cursor.clone();
+    let lights = cursor.take_while(|c| c == '.' || c == '#').to_string();
+    if lights.is_empty() {
+        return Err(cursor.error("expected indicator lights"));
+    } else if lights.len() > 32 {
+        // Part 1 packs the lights in a `u32`
+        return Err(before.error(format!(
+            "expected at most 32 indicator lights, found {}",
+            lights.len()
+        )));
+    }
+    cursor.literal("]")?;
+
+    // Buttons only toggle lights of the diagram
+    let light = |cursor: &mut Cursor| {
+        let before = cursor.clone();
+        let index = cursor.int::<usize>()?;
+        if index >= lights.len() {
+            return Err(before.error(format!(
+                "no light {}, the diagram has {}",
+                index,
+                lights.len()
+            )));
+        }
+        Ok(index)
+    };
+
+    let mut buttons = Vec::new();
+    cursor.skip_spaces();
+    while cursor.eat("(") {
+        buttons.push(parse::separated(light, ",")(cursor)?);
+        cursor.literal(")")?;
+        cursor.skip_spaces();
+    }
+    if buttons.is_empty() {
+        return Err(cursor.error("expected buttons"));
+    }
+
+    cursor.literal("{")?;
+    let joltages = parse::separated(parse::int, ",")(cursor)?;
+    cursor.literal("}")?;
+
+    Ok(Machine {
+        lights,
+        buttons,
+        joltages,
+    })
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Parsed = Vec<Machine>;
+
+    fn day(&self) -> u8 {
+        10
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new("main", TEST).part1(7).part2(33)]
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        parse::lines(input, machine)
+    }
+
+    fn part1(&self, machines: &Self::Parsed, _params: &Params) -> Result<Answer> {
+        let mut operations_per_machine = Vec::new();
+        for (i, machine) in machines.iter().enumerate() {
+            let expected_signal = machine
+                .lights
+                .chars()
+                .map(|c| if c == '#' { '1' } else { '0' })
+                .collect::<String>();
+
+            let signal_len = machine.lights.len();
+
+            let expected_signal = u32::from_str_radix(&expected_signal, 2)?;
+
+            let buttons = &machine.buttons;
+
+            let buttons_masks = buttons
+                .iter()
+                .map(|f| {
+                    f.iter()
+                        .map(|b| (1_u32 << (signal_len - 1)) >> b)
+                        .reduce(|a, b| a | b)
+                        .unwrap()
+                })
+                .collect_vec();
+
+            trace!(
+                "Expected signals: {}",
+                format_int(&expected_signal, &signal_len),
+            );
+            trace!("Buttons: {:?}", buttons);
+            trace!(
+                "Mask: {}",
+                buttons_masks
+                    .iter()
+                    .map(|f| format_int(f, &signal_len))
+                    .join(",")
+            );
+
+            // No press at all is enough for a diagram with every light off
+            let presses = 'search: {
+                for operation_number in 0..12 {
+                    for combos in buttons_masks.iter().combinations(operation_number) {
+                        let mut current_signals = 0_u32;
+                        for num in combos.iter() {
+                            current_signals ^= *num;
+                        }
+                        trace!(
+                            "[{}] => {}",
+                            combos.iter().map(|f| format_int(f, &signal_len)).join(","),
+                            format_int(&current_signals, &signal_len)
+                        );
+
+                        if current_signals == expected_signal {
+                            break 'search Some(operation_number);
+                        }
+                    }
+                }
+                None
+            };
+            let presses = presses.ok_or_else(|| {
+                anyhow!(
+                    "machine {} cannot light its diagram in fewer than 12 presses",
+                    i + 1
+                )
+            })?;
+            operations_per_machine.push(presses);
+
+            trace!("{:?}", operations_per_machine);
+        }
+
+        Ok(operations_per_machine.iter().sum::<usize>().into())
+    }
+
+    fn part2(&self, machines: &Self::Parsed, _params: &Params) -> Result<Answer> {
+        let mut operations_per_machine = Vec::new();
+        for (i, machine) in machines.iter().enumerate() {
+            let expected_signal = &machine.joltages;
+            let buttons = &machine.buttons;
+
+            debug!("Expected signals: {:?}", &expected_signal);
+            debug!("Buttons: {:?}", buttons);
+
+            let mut problem = ProblemVariables::new();
+
+            let button_vars: Vec<Variable> = (0..buttons.len())
+                .map(|_| problem.add(variable().min(0).integer()))
+                .collect();
+
+            let objective = button_vars
+                .iter()
+                .fold(Expression::from(0), |acc, &var| acc + var);
+
+            let mut solver = problem.minimise(objective).using(default_solver);
+            for (counter_idx, &target) in expected_signal.iter().enumerate() {
+                let mut expr = Expression::from(0);
+                for (button_idx, button) in buttons.iter().enumerate() {
+                    if button.contains(&counter_idx) {
+                        expr += button_vars[button_idx];
+                    }
+                }
+                solver = solver.with(expr.eq(target as i32));
+            }
+
+            let solution = solver
+                .solve()
+                .with_context(|| format!("machine {} cannot reach its joltages", i + 1))?;
+
+            let total_presses: usize = button_vars
+                .iter()
+                .map(|&var| solution.value(var) as usize)
+                .sum();
+
+            debug!("Minimum presses: {}", total_presses);
+            operations_per_machine.push(total_presses);
+        }
+
+        Ok(operations_per_machine.iter().sum::<usize>().into())
+    }
+}
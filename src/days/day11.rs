@@ -0,0 +1,112 @@
+use crate::parse::{self, Cursor};
+use crate::{debug, Answer, Example, Graph, NodeId, Params, Solution};
+use anyhow::*;
+
+const TEST: &str = "\
+aaa: you hhh
+you: bbb ccc
+bbb: ddd eee
+ccc: ddd eee fff
+ddd: ggg
+eee: out
+fff: out
+ggg: out
+hhh: ccc fff iii
+iii: out
+";
+
+const TEST_2: &str = "\
+svr: aaa bbb
+aaa: fft
+fft: ccc
+bbb: tty
+tty: ccc
+ccc: ddd eee
+ddd: hub
+hub: fff
+eee: dac
+dac: fff
+fff: ggg hhh
+ggg: out
+hhh: out
+";
+
+/// A device line such as `aaa: you hhh`, as the device and its outputs.
+fn device(cursor: &mut Cursor) -> Result<(String, Vec<String>)> {
+    let name = cursor.take_while(|c| c != ':' && !c.is_whitespace());
+    if name.is_empty() {
+        return Err(cursor.error("expected a device name"));
+    }
+    cursor.literal(":")?;
+    let outputs = parse::spaced(|cursor| Ok(cursor.word()?.to_string()))(cursor)?;
+    Ok((name.to_string(), outputs))
+}
+
+fn create_graph(input: &str) -> Result<Graph> {
+    let mut graph = Graph::directed();
+
+    for (node_name, connected_to) in parse::lines(input, device)? {
+        for connect in connected_to {
+            graph.add_edge_by_name(&node_name, &connect);
+        }
+    }
+    Ok(graph)
+}
+
+fn node(graph: &Graph, name: &str) -> Result<NodeId> {
+    graph
+        .id(name)
+        .ok_or_else(|| anyhow!("'{}' not found in the devices", name))
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Parsed = Graph;
+
+    fn day(&self) -> u8 {
+        11
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new("you", TEST).part1(5),
+            Example::new("svr", TEST_2).part2(2),
+        ]
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        create_graph(input)
+    }
+
+    fn part1(&self, graph: &Self::Parsed, _params: &Params) -> Result<Answer> {
+        let path_count = graph.count_paths(node(graph, "you")?, node(graph, "out")?)?;
+
+        Ok(path_count.into())
+    }
+
+    fn part2(&self, graph: &Self::Parsed, _params: &Params) -> Result<Answer> {
+        // Path segmentation: svr -> fft -> dac -> out
+        // Total paths = paths(svr->fft) × paths(fft->dac) × paths(dac->out)
+
+        let svr = node(graph, "svr")?;
+        let fft = node(graph, "fft")?;
+        let dac = node(graph, "dac")?;
+        let out = node(graph, "out")?;
+
+        let path_specs = vec![
+            (svr, fft, "Path 1"),
+            (fft, dac, "Path 2"),
+            (dac, out, "Path 3"),
+        ];
+
+        let mut total_memo = 1_u128;
+        for (start, end, label) in path_specs {
+            let count = graph.count_paths(start, end)?;
+            debug!("'{}' has {} paths", label, count);
+            total_memo *= count;
+        }
+
+        Ok(total_memo.into())
+    }
+}
@@ -0,0 +1,144 @@
+use crate::parse::{self, Cursor};
+use crate::{Answer, Example, Param, Params, Solution};
+use anyhow::*;
+use itertools::Itertools;
+
+const TEST: &str = "\
+0:
+###
+##.
+##.
+
+1:
+###
+##.
+.##
+
+2:
+.##
+###
+##.
+
+3:
+##.
+###
+##.
+
+4:
+###
+#..
+###
+
+5:
+###
+.#.
+###
+
+4x4: 0 0 0 0 2 0
+12x5: 1 0 1 0 2 2
+12x5: 1 0 1 0 3 2
+";
+
+#[derive(Debug, Clone)]
+pub struct Region {
+    width: u32,
+    height: u32,
+    shapes_to_fit: Vec<u32>,
+}
+
+impl Region {
+    fn new(width: u32, height: u32, shapes_to_fit: Vec<u32>) -> Self {
+        Self {
+            width,
+            height,
+            shapes_to_fit,
+        }
+    }
+
+    fn area(&self) -> u64 {
+        self.width as u64 * self.height as u64
+    }
+}
+
+/// A region line such as `12x5: 1 0 1 0 2 2`, with at most one count for each of the `shapes`.
+fn region(cursor: &mut Cursor, shapes: usize) -> Result<Region> {
+    let (width, height) = parse::pair(parse::int, "x", parse::int)(cursor)?;
+    cursor.literal(":")?;
+    cursor.skip_spaces();
+    let before = cursor.clone();
+    let counts = parse::spaced(parse::int)(cursor)?;
+    if counts.len() > shapes {
+        return Err(before.error(format!(
+            "found {} counts for {} shapes",
+            counts.len(),
+            shapes
+        )));
+    }
+    Ok(Region::new(width, height, counts))
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    /// The area of every shape, and the regions to fill.
+    type Parsed = (Vec<usize>, Vec<Region>);
+
+    fn day(&self) -> u8 {
+        12
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new("main", TEST).part1(2)]
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![Param::new(
+            "slack",
+            3,
+            "Free cells a region needs on top of the total shape area to fit them",
+        )]
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        let mut shapes = Vec::new();
+        let mut regions = Vec::new();
+        for block in parse::blocks(input) {
+            if block.lines[0].ends_with(':') {
+                parse::line(block.first_line, block.lines[0], |cursor| {
+                    cursor.int::<usize>()?;
+                    cursor.literal(":")
+                })?;
+                let shape = block.lines[1..].join("\n");
+                shapes.push(shape);
+            } else {
+                regions.extend(block.parse_lines(|cursor| region(cursor, shapes.len()))?);
+            }
+        }
+
+        let shape_sizes = shapes
+            .iter()
+            .map(|s| s.chars().filter(|c| *c == '#').count())
+            .collect_vec();
+
+        Ok((shape_sizes, regions))
+    }
+
+    fn part1(&self, (shape_sizes, regions): &Self::Parsed, params: &Params) -> Result<Answer> {
+        let slack = params.get::<u64>("slack")?;
+
+        let mut result = 0;
+        for region in regions {
+            let total_shapes_area: u64 = region
+                .shapes_to_fit
+                .iter()
+                .zip(shape_sizes)
+                .map(|(&count, &size)| count as u64 * size as u64)
+                .sum();
+            if total_shapes_area + slack <= region.area() {
+                result += 1;
+            }
+        }
+
+        Ok(result.into())
+    }
+}
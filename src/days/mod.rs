@@ -0,0 +1,216 @@
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+
+use crate::Puzzle;
+
+/// Every solved day, in order.
+pub fn all() -> Vec<&'static dyn Puzzle> {
+    vec![
+        &day01::Day01,
+        &day02::Day02,
+        &day03::Day03,
+        &day04::Day04,
+        &day05::Day05,
+        &day06::Day06,
+        &day07::Day07,
+        &day08::Day08,
+        &day09::Day09,
+        &day10::Day10,
+        &day11::Day11,
+        &day12::Day12,
+    ]
+}
+
+pub fn get(day: u8) -> Option<&'static dyn Puzzle> {
+    all().into_iter().find(|solution| solution.day() == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{InputError, Params, Part};
+    use anyhow::Result;
+
+    #[test]
+    fn days_are_registered_in_order() {
+        let days = all().iter().map(|s| s.day()).collect::<Vec<_>>();
+        assert_eq!(Some(&1), days.first());
+        assert!(days.windows(2).all(|w| w[0] < w[1]), "{:?}", days);
+        assert!(days.iter().all(|day| (1..=25).contains(day)));
+    }
+
+    #[test]
+    fn examples_give_the_expected_answers() {
+        let mut failures = Vec::new();
+        for solution in all() {
+            if solution.examples().is_empty() {
+                failures.push(format!("day {:02} has no example", solution.day()));
+            }
+            for example in solution.examples() {
+                for part in Part::ALL {
+                    let Some(expected) = example.expected(part) else {
+                        continue;
+                    };
+                    match example.run(solution, part) {
+                        Ok(answer) if answer == *expected => {}
+                        Ok(answer) => failures.push(format!(
+                            "day {:02} example '{}' part {}: expected {}, got {}",
+                            solution.day(),
+                            example.name,
+                            part,
+                            expected,
+                            answer
+                        )),
+                        Err(e) => failures.push(format!(
+                            "day {:02} example '{}' part {}: {:#}",
+                            solution.day(),
+                            example.name,
+                            part,
+                            e
+                        )),
+                    }
+                }
+            }
+        }
+
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+
+    #[test]
+    fn malformed_inputs_point_at_the_bad_line() {
+        let solution = get(1).unwrap();
+        let params = Params::new(&solution.params());
+        let error = solution
+            .solve(Part::Two, "L68\nL30\nR4x8\n", &params)
+            .unwrap_err()
+            .downcast::<InputError>()
+            .unwrap();
+        assert_eq!(
+            "day 01, line 3, column 3: unexpected 'x'",
+            error.to_string()
+        );
+        assert_eq!("R4x8", error.text);
+
+        let error = get(7)
+            .unwrap()
+            .solve(Part::Two, "...\n.^.\n", &Params::default())
+            .unwrap_err()
+            .downcast::<InputError>()
+            .unwrap();
+        assert_eq!(
+            "day 07 part 2, line 1, column 1: expected a start position 'S'",
+            error.to_string()
+        );
+
+        let day07 = get(7).unwrap();
+        let error = day07
+            .solve(Part::One, "...\n.^.\n", &Params::default())
+            .unwrap_err()
+            .downcast::<InputError>()
+            .unwrap();
+        assert_eq!(
+            "day 07 part 1, line 1, column 1: expected a start position 'S'",
+            error.to_string()
+        );
+        let error = day07
+            .solve(Part::One, ".S.\n.#.\n", &Params::default())
+            .unwrap_err()
+            .downcast::<InputError>()
+            .unwrap();
+        assert_eq!(
+            "day 07, line 2, column 2: unexpected '#', expected '.', '^' or 'S'",
+            error.to_string()
+        );
+
+        let error = get(12)
+            .unwrap()
+            .solve(Part::One, "0:\n#\n\n2x2: 1 1\n", &Params::default())
+            .unwrap_err()
+            .downcast::<InputError>()
+            .unwrap();
+        assert_eq!(
+            "day 12, line 4, column 6: found 2 counts for 1 shapes",
+            error.to_string()
+        );
+
+        let day06 = get(6).unwrap();
+        for (input, message) in [
+            (
+                "1 2\n3\n* +\n",
+                "line 2, column 2: expected 2 numbers, found 1",
+            ),
+            (
+                "1 2\n3 4\n*\n",
+                "line 3, column 2: expected 2 operators, found 1",
+            ),
+        ] {
+            let error = day06
+                .solve(Part::One, input, &Params::default())
+                .unwrap_err()
+                .downcast::<InputError>()
+                .unwrap();
+            assert_eq!(format!("day 06, {}", message), error.to_string());
+        }
+    }
+
+    #[test]
+    fn day03_checks_the_number_of_digits() -> Result<()> {
+        let solution = get(3).unwrap();
+        let mut params = Params::new(&solution.params());
+        for (digits, message) in [
+            (0, "digits must be at least 1"),
+            (20, "a bank of 19 batteries can't turn on 20"),
+        ] {
+            params.set("digits", digits)?;
+            let error = solution
+                .solve(Part::Two, "9999999999999999999\n", &params)
+                .unwrap_err();
+            assert_eq!(message, error.to_string());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn day10_rejects_machines_it_cannot_represent() {
+        let solution = get(10).unwrap();
+        let error = |input: &str| {
+            solution
+                .solve(Part::One, input, &Params::default())
+                .unwrap_err()
+                .to_string()
+        };
+        assert_eq!(
+            "day 10, line 1, column 7: no light 5, the diagram has 2",
+            error("[.#] (5) {1,2}\n")
+        );
+        assert_eq!(
+            "day 10, line 1, column 2: expected at most 32 indicator lights, found 34",
+            error(&format!("[{}] (0) {{1}}\n", ".".repeat(34)))
+        );
+    }
+
+    #[test]
+    fn empty_inputs_fail_every_part() {
+        let params = Params::default();
+        for part in Part::ALL {
+            assert!(get(7).unwrap().solve(part, "", &params).is_err());
+            assert!(get(9).unwrap().solve(part, "", &params).is_err());
+        }
+
+        // Both parts find the start below the first row
+        let day07 = get(7).unwrap();
+        let manifold = "...\n.S.\n.^.\n";
+        assert_eq!(1, day07.solve(Part::One, manifold, &params).unwrap());
+        assert_eq!(2, day07.solve(Part::Two, manifold, &params).unwrap());
+    }
+}
@@ -0,0 +1,466 @@
+//! Arithmetic of a circular dial numbered `0..size`, where turning right increases the number.
+//!
+//! Rotations are signed numbers of clicks: positive to the right, negative to the left.
+
+use crate::parse::{self, Cursor};
+use anyhow::*;
+use std::collections::VecDeque;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// Position of a dial at `position` after turning it by `clicks`.
+pub fn turn(position: i64, size: i64, clicks: i64) -> Result<i64> {
+    let end = position
+        .checked_add(clicks)
+        .ok_or_else(|| anyhow!("turning by {} clicks overflows", clicks))?;
+    Ok(end.rem_euclid(size))
+}
+
+/// How many times a dial at `position` points at 0 while turning by `clicks`, counting the click it stops on.
+///
+/// Takes constant time whatever the number of clicks.
+pub fn zero_passes(position: i64, size: i64, clicks: i64) -> u64 {
+    let position = position.rem_euclid(size);
+    // Turning left from `position` meets 0 as often as turning right from its mirror image
+    let offset = if clicks >= 0 {
+        position
+    } else {
+        (size - position) % size
+    };
+    (offset as u64 + clicks.unsigned_abs()) / size as u64
+}
+
+/// Which clicks of a rotation count as reaching a target.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Counting {
+    /// Only the position a rotation stops on, even a rotation of no clicks.
+    #[default]
+    Land,
+    /// Every click but the one a rotation stops on.
+    Pass,
+    /// Every click.
+    Both,
+}
+
+impl FromStr for Counting {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "land" => Ok(Counting::Land),
+            "pass" => Ok(Counting::Pass),
+            "both" => Ok(Counting::Both),
+            _ => bail!("invalid counting '{}', expected land, pass or both", s),
+        }
+    }
+}
+
+/// A dial that counts how often it reaches each of its targets, and remembers where each rotation left it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dial {
+    size: i64,
+    counting: Counting,
+    /// Each target with how often it was reached, in the order they were given.
+    targets: Vec<(i64, u64)>,
+    /// The start position, then the position after each rotation.
+    trajectory: Vec<i64>,
+}
+
+impl Dial {
+    /// A dial of `size` positions pointing at `start`, counting landings on 0.
+    pub fn new(size: i64, start: i64) -> Result<Self> {
+        ensure!(size > 0, "the dial needs at least one position");
+        Ok(Self {
+            size,
+            counting: Counting::Land,
+            targets: vec![(0, 0)],
+            trajectory: vec![start.rem_euclid(size)],
+        })
+    }
+
+    /// Counts `targets` instead of 0, each once even when repeated.
+    pub fn targets(mut self, targets: impl IntoIterator<Item = i64>) -> Self {
+        self.targets.clear();
+        for target in targets {
+            let target = target.rem_euclid(self.size);
+            if !self.targets.iter().any(|&(t, _)| t == target) {
+                self.targets.push((target, 0));
+            }
+        }
+        self
+    }
+
+    pub fn counting(mut self, counting: Counting) -> Self {
+        self.counting = counting;
+        self
+    }
+
+    pub fn position(&self) -> i64 {
+        *self.trajectory.last().unwrap()
+    }
+
+    /// Turns the dial by `clicks`, in constant time.
+    pub fn turn(&mut self, clicks: i64) -> Result<()> {
+        let position = self.position();
+        let end = turn(position, self.size, clicks)?;
+        for (target, count) in &mut self.targets {
+            let landed = (end == *target) as u64;
+            // Every click is a pass at 0 once the dial is shifted so the target is 0
+            let all = zero_passes(position - *target, self.size, clicks);
+            *count += match self.counting {
+                Counting::Land => landed,
+                Counting::Pass if clicks == 0 => 0,
+                Counting::Pass => all - landed,
+                Counting::Both => all,
+            };
+        }
+        self.trajectory.push(end);
+        Ok(())
+    }
+
+    /// How often `target` was reached, `None` when it is not a target.
+    pub fn count(&self, target: i64) -> Option<u64> {
+        let target = target.rem_euclid(self.size);
+        self.targets
+            .iter()
+            .find(|&&(t, _)| t == target)
+            .map(|&(_, count)| count)
+    }
+
+    /// Each target with how often it was reached.
+    pub fn counts(&self) -> &[(i64, u64)] {
+        &self.targets
+    }
+
+    pub fn total(&self) -> u64 {
+        self.targets.iter().map(|&(_, count)| count).sum()
+    }
+
+    /// The start position, then the position after each rotation.
+    pub fn trajectory(&self) -> &[i64] {
+        &self.trajectory
+    }
+}
+
+/// A rotation of one dial of a lock, such as `2:R15`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Instruction {
+    /// Index of the dial, from 0.
+    pub dial: usize,
+    pub clicks: i64,
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.dial > 0 {
+            write!(f, "{}:", self.dial + 1)?;
+        }
+        let direction = if self.clicks < 0 { 'L' } else { 'R' };
+        write!(f, "{}{}", direction, self.clicks.unsigned_abs())
+    }
+}
+
+/// Parses an instruction such as `L68`, addressed to the first dial, or `2:R15`, numbering dials from 1.
+pub fn instruction(cursor: &mut Cursor) -> Result<Instruction> {
+    let dial = if cursor.peek().is_some_and(|c| c.is_ascii_digit()) {
+        let before = cursor.clone();
+        let dial = cursor.int::<usize>()?;
+        if dial == 0 {
+            return Err(before.error("dials are numbered from 1"));
+        }
+        cursor.literal(":")?;
+        dial - 1
+    } else {
+        0
+    };
+
+    let direction = match cursor.one_of(&['L', 'R'])? {
+        'R' => 1,
+        _ => -1,
+    };
+    let before = cursor.clone();
+    let clicks = cursor
+        .int::<i64>()?
+        .checked_mul(direction)
+        .ok_or_else(|| before.error("too many clicks"))?;
+    Ok(Instruction { dial, clicks })
+}
+
+/// Couples two dials of a lock: every click of `driver` turns `driven` by `ratio` clicks, backwards when negative.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Gear {
+    pub driver: usize,
+    pub driven: usize,
+    pub ratio: i64,
+}
+
+impl FromStr for Gear {
+    type Err = Error;
+
+    /// Parses a gear such as `1>2`, or `1>3*-2` for a ratio, numbering dials from 1.
+    fn from_str(s: &str) -> Result<Self> {
+        parse::line(1, s.trim(), |cursor| {
+            let (driver, driven) =
+                parse::pair(parse::int::<usize>, ">", parse::int::<usize>)(cursor)?;
+            let ratio = if cursor.eat("*") { cursor.int()? } else { 1 };
+            if driver == 0 || driven == 0 {
+                return Err(cursor.error("dials are numbered from 1"));
+            }
+            Ok(Gear {
+                driver: driver - 1,
+                driven: driven - 1,
+                ratio,
+            })
+        })
+        .with_context(|| format!("invalid gear '{}', expected e.g. 1>2 or 1>3*-2", s.trim()))
+    }
+}
+
+/// Several dials, some of which may be turned by others through gears.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lock {
+    dials: Vec<Dial>,
+    gears: Vec<Gear>,
+}
+
+impl Lock {
+    pub fn new(dials: Vec<Dial>) -> Result<Self> {
+        ensure!(!dials.is_empty(), "the lock needs at least one dial");
+        Ok(Self {
+            dials,
+            gears: Vec::new(),
+        })
+    }
+
+    pub fn gear(mut self, gear: Gear) -> Result<Self> {
+        for dial in [gear.driver, gear.driven] {
+            ensure!(
+                dial < self.dials.len(),
+                "no dial {}, the lock has {}",
+                dial + 1,
+                self.dials.len()
+            );
+        }
+        ensure!(
+            gear.driver != gear.driven,
+            "dial {} cannot drive itself",
+            gear.driver + 1
+        );
+        self.gears.push(gear);
+        Ok(self)
+    }
+
+    /// Turns the addressed dial, then the dials its gears drive, each dial turning at most once.
+    pub fn turn(&mut self, instruction: Instruction) -> Result<()> {
+        ensure!(
+            instruction.dial < self.dials.len(),
+            "no dial {}, the lock has {}",
+            instruction.dial + 1,
+            self.dials.len()
+        );
+
+        let mut turned = vec![false; self.dials.len()];
+        let mut queue = VecDeque::from([(instruction.dial, instruction.clicks)]);
+        while let Some((dial, clicks)) = queue.pop_front() {
+            if std::mem::replace(&mut turned[dial], true) {
+                continue;
+            }
+            self.dials[dial].turn(clicks)?;
+
+            for gear in self.gears.iter().filter(|g| g.driver == dial) {
+                let driven = clicks.checked_mul(gear.ratio).ok_or_else(|| {
+                    anyhow!(
+                        "turning dial {} by {} overflows",
+                        gear.driven + 1,
+                        clicks as i128 * gear.ratio as i128
+                    )
+                })?;
+                queue.push_back((gear.driven, driven));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn dials(&self) -> &[Dial] {
+        &self.dials
+    }
+
+    /// The position of each dial.
+    pub fn combination(&self) -> Vec<i64> {
+        self.dials.iter().map(Dial::position).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Turns the dial one click at a time, as a reference for the closed forms.
+    fn simulate(mut position: i64, size: i64, clicks: i64) -> (i64, u64) {
+        let mut zeros = 0;
+        for _ in 0..clicks.unsigned_abs() {
+            position = (position + clicks.signum()).rem_euclid(size);
+            if position == 0 {
+                zeros += 1;
+            }
+        }
+        (position, zeros)
+    }
+
+    #[test]
+    fn counts_passes_through_zero() {
+        assert_eq!(1, zero_passes(50, 100, -68));
+        assert_eq!(0, zero_passes(82, 100, -30));
+        assert_eq!(1, zero_passes(52, 100, 48));
+        assert_eq!(0, zero_passes(0, 100, -5));
+        assert_eq!(10, zero_passes(50, 100, 1000));
+        assert_eq!(10_000_000, zero_passes(0, 100, -1_000_000_000));
+        assert_eq!(0, zero_passes(3, 100, 0));
+        assert_eq!(82, turn(50, 100, -68).unwrap());
+        assert!(turn(1, 100, i64::MAX).is_err());
+    }
+
+    #[test]
+    fn counts_each_target_and_mode() -> Result<()> {
+        let rotations = [-68, -30, 48, -5, 60, -55, -1, -99, 14, -82];
+        let run = |counting, targets: &[i64]| -> Result<Dial> {
+            let mut dial = Dial::new(100, 50)?
+                .counting(counting)
+                .targets(targets.iter().copied());
+            for clicks in rotations {
+                dial.turn(clicks)?;
+            }
+            Ok(dial)
+        };
+
+        assert_eq!(3, run(Counting::Land, &[0])?.total());
+        assert_eq!(3, run(Counting::Pass, &[0])?.total());
+        assert_eq!(6, run(Counting::Both, &[0])?.total());
+
+        let dial = run(Counting::Both, &[0, 32, 100])?;
+        assert_eq!(&[(0, 6), (32, 5)], dial.counts());
+        assert_eq!(Some(5), dial.count(132));
+        assert_eq!(None, dial.count(1));
+        assert_eq!(
+            &[50, 82, 52, 0, 95, 55, 0, 99, 0, 14, 32],
+            dial.trajectory()
+        );
+        assert_eq!(32, dial.position());
+
+        assert!(Dial::new(0, 0).is_err());
+        let mut dial = Dial::new(100, 1)?;
+        assert!(dial.turn(i64::MAX).is_err());
+        assert_eq!(&[1], dial.trajectory());
+        assert_eq!(Counting::Pass, "pass".parse()?);
+        assert!("twice".parse::<Counting>().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn parses_instructions_and_gears() -> Result<()> {
+        let parse = |text| parse::line(1, text, instruction);
+        assert_eq!(
+            Instruction {
+                dial: 0,
+                clicks: -68
+            },
+            parse("L68")?
+        );
+        assert_eq!(
+            Instruction {
+                dial: 1,
+                clicks: 15
+            },
+            parse("2:R15")?
+        );
+        assert_eq!("2:R15", parse("2:R15")?.to_string());
+        assert_eq!(
+            "line 1, column 1: dials are numbered from 1",
+            parse("0:R1").unwrap_err().to_string()
+        );
+        assert!(parse("L-9223372036854775808").is_err());
+        assert!(parse("2R15").is_err());
+
+        assert_eq!(
+            Gear {
+                driver: 0,
+                driven: 2,
+                ratio: -2
+            },
+            "1>3*-2".parse()?
+        );
+        assert_eq!(1, " 2>1 ".parse::<Gear>()?.ratio);
+        assert!("1>0".parse::<Gear>().is_err());
+        assert!("1-2".parse::<Gear>().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn gears_turn_the_driven_dials() -> Result<()> {
+        let dials = (0..3)
+            .map(|_| Dial::new(10, 0).map(|d| d.counting(Counting::Both)))
+            .collect::<Result<Vec<_>>>()?;
+        let mut lock = Lock::new(dials)?
+            .gear("1>2*3".parse()?)?
+            .gear("2>3*-1".parse()?)?
+            .gear("3>1".parse()?)?;
+
+        lock.turn(Instruction { dial: 0, clicks: 4 })?;
+        assert_eq!(vec![4, 2, 8], lock.combination());
+        lock.turn(Instruction { dial: 2, clicks: 2 })?;
+        assert_eq!(vec![6, 8, 0], lock.combination());
+        assert_eq!(
+            vec![0, 1, 2],
+            lock.dials().iter().map(Dial::total).collect::<Vec<_>>()
+        );
+
+        assert!(lock.turn(Instruction { dial: 3, clicks: 1 }).is_err());
+        assert!(lock.clone().gear("1>1".parse()?).is_err());
+        assert!(lock.clone().gear("1>4".parse()?).is_err());
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn matches_the_step_simulation(
+            size in 1i64..200,
+            start in 0i64..200,
+            rotations in prop::collection::vec(-1000i64..1000, 0..50),
+        ) {
+            let mut position = start % size;
+            let mut zeros = 0;
+            let mut simulated = (position, 0);
+            for clicks in rotations {
+                zeros += zero_passes(position, size, clicks);
+                position = turn(position, size, clicks).unwrap();
+
+                let (next, passes) = simulate(simulated.0, size, clicks);
+                simulated = (next, simulated.1 + passes);
+                prop_assert_eq!(simulated, (position, zeros));
+            }
+        }
+
+        #[test]
+        fn modes_add_up(
+            size in 1i64..50,
+            start in -100i64..100,
+            target in 0i64..50,
+            rotations in prop::collection::vec(-500i64..500, 0..20),
+        ) {
+            let dial = |counting| Dial::new(size, start).unwrap().counting(counting).targets([target]);
+            let (mut land, mut pass, mut both) = (dial(Counting::Land), dial(Counting::Pass), dial(Counting::Both));
+            for &clicks in &rotations {
+                land.turn(clicks).unwrap();
+                pass.turn(clicks).unwrap();
+                both.turn(clicks).unwrap();
+            }
+            // A rotation of no clicks lands without clicking
+            let still = land.trajectory().windows(2).zip(&rotations)
+                .filter(|&(positions, &clicks)| clicks == 0 && positions[1] == target % size)
+                .count() as u64;
+            prop_assert_eq!(land.total() + pass.total(), both.total() + still);
+            prop_assert_eq!(rotations.len() + 1, both.trajectory().len());
+        }
+    }
+}
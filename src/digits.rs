@@ -0,0 +1,317 @@
+//! Numbers described by their digits in some base, enumerated by construction rather than by scanning a range.
+
+use std::ops::RangeInclusive;
+
+/// Digits of `n` in `base`, most significant first, `[0]` for 0.
+///
+/// Panics unless `base` is at least 2.
+pub fn digits(mut n: u64, base: u32) -> Vec<u32> {
+    assert!(base >= 2, "base {} has no digits", base);
+    let mut digits = vec![(n % base as u64) as u32];
+    n /= base as u64;
+    while n > 0 {
+        digits.push((n % base as u64) as u32);
+        n /= base as u64;
+    }
+    digits.reverse();
+    digits
+}
+
+/// Number of digits of `u64::MAX` in `base`, the longest any number can have.
+fn max_digits(base: u32) -> u32 {
+    digits(u64::MAX, base).len() as u32
+}
+
+/// Number whose digits in `base` are `digits`, as `u128` so that callers can check it against a range.
+fn number(digits: impl IntoIterator<Item = u32>, base: u32) -> u128 {
+    digits
+        .into_iter()
+        .fold(0, |n, digit| n * base as u128 + digit as u128)
+}
+
+/// Numbers in `range` whose digits in `base` are one block of `period` digits repeated `times` times, in increasing order.
+///
+/// The block cannot start with 0, so `0101` is not a repetition of `01`.
+pub fn repeated_blocks(
+    range: RangeInclusive<u64>,
+    period: u32,
+    times: u32,
+    base: u32,
+) -> impl Iterator<Item = u64> {
+    let (factor, blocks) = if period == 0 || times == 0 || period * times > max_digits(base) {
+        (1, RangeInclusive::new(1, 0))
+    } else {
+        // Repeating a block multiplies it by 1 followed by `period - 1` zeros, `times` times: 10101 for 2 and 3
+        let shift = (base as u128).pow(period);
+        let factor = (0..times).fold(0, |factor, _| factor * shift + 1);
+        let first = (*range.start() as u128)
+            .div_ceil(factor)
+            .max(shift / base as u128);
+        let last = (*range.end() as u128 / factor).min(shift - 1);
+        (factor, first..=last)
+    };
+
+    // Both bounds keep `block * factor` within the range, hence within `u64`
+    blocks.map(move |block| (block * factor) as u64)
+}
+
+/// A property of the digits of a number.
+pub trait Pattern {
+    /// Whether `digits`, most significant first and without leading zeros, have the property.
+    fn matches(&self, digits: &[u32]) -> bool;
+
+    /// The matching numbers of `range` in increasing order, found by checking every number unless the pattern can build them.
+    fn find(&self, range: RangeInclusive<u64>, base: u32) -> Vec<u64> {
+        scan(self, range, base)
+    }
+
+    /// The matching numbers of each range in turn, so a number is listed once per range it is in.
+    fn list(&self, ranges: &[RangeInclusive<u64>], base: u32) -> Vec<u64> {
+        ranges
+            .iter()
+            .flat_map(|range| self.find(range.clone(), base))
+            .collect()
+    }
+
+    fn count(&self, ranges: &[RangeInclusive<u64>], base: u32) -> usize {
+        ranges
+            .iter()
+            .map(|range| self.find(range.clone(), base).len())
+            .sum()
+    }
+
+    fn sum(&self, ranges: &[RangeInclusive<u64>], base: u32) -> u128 {
+        self.list(ranges, base).into_iter().map(u128::from).sum()
+    }
+}
+
+/// The numbers of `range` matching `pattern`, checking each of them.
+pub fn scan<P: Pattern + ?Sized>(pattern: &P, range: RangeInclusive<u64>, base: u32) -> Vec<u64> {
+    range
+        .filter(|&n| pattern.matches(&digits(n, base)))
+        .collect()
+}
+
+/// Numbers that read the same both ways, such as `12321`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Palindrome;
+
+impl Pattern for Palindrome {
+    fn matches(&self, digits: &[u32]) -> bool {
+        digits.iter().eq(digits.iter().rev())
+    }
+
+    fn find(&self, range: RangeInclusive<u64>, base: u32) -> Vec<u64> {
+        let (start, end) = (*range.start() as u128, *range.end() as u128);
+        let mut numbers = Vec::new();
+        for length in 1..=max_digits(base) {
+            // A palindrome grows with its first half, so the halves in range are contiguous
+            let half = length.div_ceil(2);
+            let mirror = |first: u128| {
+                let digits = digits(first as u64, base);
+                let tail = digits[..(length - half) as usize].iter().rev().copied();
+                number(digits.iter().copied().chain(tail), base)
+            };
+            let lowest = if length == 1 {
+                0
+            } else {
+                (base as u128).pow(half - 1)
+            };
+            let highest = (base as u128).pow(half) - 1;
+            let shift = (base as u128).pow(length - half);
+
+            let mut first = (start / shift).max(lowest);
+            if first <= highest && mirror(first) < start {
+                first += 1;
+            }
+            let mut last = (end / shift).min(highest);
+            if last >= first && mirror(last) > end {
+                last -= 1;
+            }
+            if first <= last {
+                numbers.extend((first..=last).map(|first| mirror(first) as u64));
+            }
+        }
+        numbers
+    }
+}
+
+/// Numbers made of one block repeated a number of times in `times`, such as `123123` for 2.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Repeated {
+    pub times: RangeInclusive<u32>,
+}
+
+impl Repeated {
+    pub fn exactly(times: u32) -> Self {
+        Self {
+            times: times..=times,
+        }
+    }
+
+    pub fn at_least(times: u32) -> Self {
+        Self {
+            times: times..=u32::MAX,
+        }
+    }
+}
+
+impl Pattern for Repeated {
+    fn matches(&self, digits: &[u32]) -> bool {
+        (1..=digits.len()).any(|period| {
+            digits.len().is_multiple_of(period)
+                && self.times.contains(&((digits.len() / period) as u32))
+                && digits
+                    .chunks(period)
+                    .all(|block| block == &digits[..period])
+        })
+    }
+
+    fn find(&self, range: RangeInclusive<u64>, base: u32) -> Vec<u64> {
+        // Blocks never start with 0, but 0 alone is a block of its own
+        let mut numbers = Vec::new();
+        if *range.start() == 0 && self.times.contains(&1) {
+            numbers.push(0);
+        }
+        for length in 1..=max_digits(base) {
+            for period in (1..=length).filter(|&p| length.is_multiple_of(p)) {
+                if self.times.contains(&(length / period)) {
+                    numbers.extend(repeated_blocks(
+                        range.clone(),
+                        period,
+                        length / period,
+                        base,
+                    ));
+                }
+            }
+        }
+
+        // `222222` repeats `2`, `22` and `222`
+        numbers.sort_unstable();
+        numbers.dedup();
+        numbers
+    }
+}
+
+/// Numbers whose digits never decrease from left to right, such as `1229`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NonDecreasing;
+
+impl Pattern for NonDecreasing {
+    fn matches(&self, digits: &[u32]) -> bool {
+        digits.windows(2).all(|pair| pair[0] <= pair[1])
+    }
+
+    fn find(&self, range: RangeInclusive<u64>, base: u32) -> Vec<u64> {
+        let (start, end) = (*range.start() as u128, *range.end() as u128);
+        let mut numbers = Vec::new();
+        if start == 0 {
+            numbers.push(0);
+        }
+        for length in 1..=max_digits(base) {
+            extend(&mut numbers, 0, 1, length, (start, end), base);
+        }
+        return numbers;
+
+        /// Completes `prefix`, whose last digit is `smallest`, with `remaining` digits, smallest numbers first.
+        fn extend(
+            numbers: &mut Vec<u64>,
+            prefix: u128,
+            smallest: u32,
+            remaining: u32,
+            (start, end): (u128, u128),
+            base: u32,
+        ) {
+            if remaining == 0 {
+                if (start..=end).contains(&prefix) {
+                    numbers.push(prefix as u64);
+                }
+                return;
+            }
+
+            for digit in smallest..base {
+                let prefix = prefix * base as u128 + digit as u128;
+                let shift = (base as u128).pow(remaining - 1);
+                // Repeating the digit gives the smallest completion, the largest digit the largest one
+                let lowest = (prefix - digit as u128) * shift
+                    + number(std::iter::repeat_n(digit, remaining as usize), base);
+                let highest = (prefix + 1) * shift - 1;
+                if lowest > end {
+                    return;
+                }
+                if highest >= start {
+                    extend(numbers, prefix, digit, remaining - 1, (start, end), base);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn builds_repeated_blocks() {
+        let blocks =
+            |range, period, times| repeated_blocks(range, period, times, 10).collect::<Vec<_>>();
+        assert_eq!(vec![11, 22], blocks(11..=22, 1, 2));
+        assert_eq!(vec![1010], blocks(998..=1012, 2, 2));
+        assert_eq!(vec![111], blocks(100..=115, 1, 3));
+        assert!(blocks(1..=9, 1, 2).is_empty());
+        assert_eq!(
+            vec![18_446_744_071_844_674_407],
+            blocks(18_446_744_071_844_674_407..=u64::MAX, 10, 2)
+        );
+        assert!(blocks(0..=u64::MAX, 7, 3).is_empty());
+        assert_eq!(
+            vec![0b1010, 0b1111],
+            repeated_blocks(0..=15, 2, 2, 2).collect::<Vec<_>>()
+        );
+
+        let longest = Repeated::at_least(19).find(0..=u64::MAX, 10);
+        assert_eq!(10, longest.len());
+        assert_eq!(Some(&11_111_111_111_111_111_111), longest.last());
+        assert_eq!(
+            vec![222222],
+            Repeated::at_least(2).find(222220..=222224, 10)
+        );
+    }
+
+    #[test]
+    fn counts_sums_and_lists_over_ranges() {
+        let ranges = [1..=30, 95..=121];
+        assert_eq!(
+            vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 11, 22, 99, 101, 111, 121],
+            Palindrome.list(&ranges, 10)
+        );
+        assert_eq!(vec![11, 22, 99], Repeated::exactly(2).list(&ranges, 10));
+        assert_eq!(vec![111], Repeated::exactly(3).list(&ranges, 10));
+        assert_eq!(36, NonDecreasing.count(&ranges, 10));
+        assert_eq!(11 + 22 + 99, Repeated::exactly(2).sum(&ranges, 10));
+
+        // 5 is 101 in binary and 9 is 1001
+        assert_eq!(vec![0, 1, 3, 5, 7, 9], Palindrome.find(0..=10, 2));
+        assert_eq!(vec![10, 15], Repeated::exactly(2).find(9..=15, 2));
+        assert_eq!(vec![1, 2, 3], digits(0x123, 16));
+    }
+
+    proptest! {
+        #[test]
+        fn builds_what_a_scan_finds(
+            start in 0u64..100_000,
+            length in 0u64..3_000,
+            base in 2u32..17,
+            times in 1u32..4,
+        ) {
+            let range = start..=start + length;
+            prop_assert_eq!(scan(&Palindrome, range.clone(), base), Palindrome.find(range.clone(), base));
+            prop_assert_eq!(scan(&NonDecreasing, range.clone(), base), NonDecreasing.find(range.clone(), base));
+            let exactly = Repeated::exactly(times);
+            prop_assert_eq!(scan(&exactly, range.clone(), base), exactly.find(range.clone(), base));
+            let at_least = Repeated::at_least(times);
+            prop_assert_eq!(scan(&at_least, range.clone(), base), at_least.find(range, base));
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use crate::Part;
+use std::fmt::{Display, Formatter};
+
+/// A malformed input, pointing at the offending line and column.
+///
+/// Parsers fill in the position, and [`crate::Puzzle::solve`] the day and part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputError {
+    pub day: Option<u8>,
+    pub part: Option<Part>,
+    /// 1-based line number in the input.
+    pub line: usize,
+    /// 1-based column of the offending character.
+    pub column: usize,
+    /// The whole offending line.
+    pub text: String,
+    pub message: String,
+}
+
+impl InputError {
+    pub fn new(line: usize, column: usize, text: impl Into<String>, message: impl Display) -> Self {
+        Self {
+            day: None,
+            part: None,
+            line,
+            column,
+            text: text.into(),
+            message: message.to_string(),
+        }
+    }
+
+    /// Number of characters of the token starting at `column`: a signed word, or a single character.
+    fn token_length(&self) -> usize {
+        let mut rest = self.text.chars().skip(self.column - 1).peekable();
+        let sign = rest.next_if(|&c| c == '-' || c == '+').is_some() as usize;
+        let word = rest.take_while(|c| c.is_alphanumeric()).count();
+        (sign + word).max(1)
+    }
+
+    /// The error followed by the offending line, with a caret under the bad token.
+    pub fn diagnostic(&self) -> String {
+        let number = self.line.to_string();
+        let margin = " ".repeat(number.len());
+        format!(
+            "{}\n{} |\n{} | {}\n{} | {}{}",
+            self,
+            margin,
+            number,
+            self.text,
+            margin,
+            " ".repeat(self.column - 1),
+            "^".repeat(self.token_length())
+        )
+    }
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match (self.day, self.part) {
+            (Some(day), Some(part)) => write!(f, "day {:02} part {}, ", day, part)?,
+            (Some(day), None) => write!(f, "day {:02}, ", day)?,
+            _ => {}
+        }
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl std::error::Error for InputError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_a_diagnostic() {
+        let mut error = InputError::new(12, 3, "L5X30", "expected a direction");
+        assert_eq!("line 12, column 3: expected a direction", error.to_string());
+
+        error.day = Some(1);
+        error.part = Some(Part::Two);
+        assert_eq!(
+            "day 01 part 2, line 12, column 3: expected a direction\n   |\n12 | L5X30\n   |   ^^^",
+            error.diagnostic()
+        );
+
+        let error = InputError::new(1, 2, "7;-1", "expected ','");
+        assert!(error.diagnostic().ends_with("\n  |  ^"));
+        let error = InputError::new(1, 3, "7;-1", "too small");
+        assert!(error.diagnostic().ends_with("\n  |   ^^"));
+        let error = InputError::new(1, 2, "7", "unexpected end of line");
+        assert!(error.diagnostic().ends_with("\n  |  ^"));
+    }
+}
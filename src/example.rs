@@ -0,0 +1,110 @@
+use crate::{Answer, ParamValue, Params, Part, Puzzle};
+use anyhow::*;
+
+/// A named example input from the puzzle text, with the answers it is expected to give.
+#[derive(Debug, Clone)]
+pub struct Example {
+    pub name: &'static str,
+    pub input: &'static str,
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+    pub params: Vec<(&'static str, ParamValue)>,
+}
+
+impl Example {
+    pub fn new(name: &'static str, input: &'static str) -> Self {
+        Self {
+            name,
+            input,
+            part1: None,
+            part2: None,
+            params: Vec::new(),
+        }
+    }
+
+    pub fn part1(mut self, answer: impl Into<Answer>) -> Self {
+        self.part1 = Some(answer.into());
+        self
+    }
+
+    pub fn part2(mut self, answer: impl Into<Answer>) -> Self {
+        self.part2 = Some(answer.into());
+        self
+    }
+
+    /// Overrides a puzzle parameter for this example, see [`crate::Solution::params`].
+    pub fn param(mut self, name: &'static str, value: impl Into<ParamValue>) -> Self {
+        self.params.push((name, value.into()));
+        self
+    }
+
+    pub fn expected(&self, part: Part) -> Option<&Answer> {
+        match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        }
+    }
+
+    /// The parameters of `solution` with this example's overrides applied.
+    pub fn resolve_params(&self, solution: &dyn Puzzle) -> Result<Params> {
+        let mut params = Params::new(&solution.params());
+        for (name, value) in &self.params {
+            params
+                .set(name, value.clone())
+                .with_context(|| format!("in example '{}'", self.name))?;
+        }
+        Ok(params)
+    }
+
+    pub fn run(&self, solution: &dyn Puzzle, part: Part) -> Result<Answer> {
+        let params = self.resolve_params(solution)?;
+        solution.solve(part, self.input, &params)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Param, Solution};
+
+    struct Count;
+
+    impl Solution for Count {
+        type Parsed = usize;
+
+        fn day(&self) -> u8 {
+            0
+        }
+
+        fn params(&self) -> Vec<Param> {
+            vec![Param::new("offset", 1, "added to the line count")]
+        }
+
+        fn parse(&self, input: &str) -> Result<usize> {
+            Ok(input.lines().count())
+        }
+
+        fn part1(&self, lines: &usize, params: &Params) -> Result<Answer> {
+            Ok((lines + params.get::<usize>("offset")?).into())
+        }
+    }
+
+    #[test]
+    fn runs_with_its_own_params() -> Result<()> {
+        let example = Example::new("two lines", "a\nb\n").part1(2);
+        assert_eq!(Some(&Answer::from(2)), example.expected(Part::One));
+        assert_eq!(None, example.expected(Part::Two));
+        assert_eq!(3, example.run(&Count, Part::One)?);
+
+        let example = example.param("offset", 0);
+        assert_eq!(2, example.run(&Count, Part::One)?);
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_undeclared_params() {
+        let example = Example::new("typo", "").param("ofset", 0);
+        let error = example.run(&Count, Part::One).unwrap_err();
+        assert!(format!("{:#}", error).contains("in example 'typo'"));
+    }
+}
@@ -0,0 +1,229 @@
+use std::fmt::{Debug, Display, Formatter};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// Integer type usable as the coordinates of a vector.
+pub trait Coordinate:
+    Copy + Ord + Debug + Display + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+    /// `|self - other|`, widened so that it never overflows.
+    fn distance_to(self, other: Self) -> u128;
+}
+
+macro_rules! impl_coordinate {
+    ($($t:ty),*) => {
+        $(
+            impl Coordinate for $t {
+                fn distance_to(self, other: Self) -> u128 {
+                    self.abs_diff(other) as u128
+                }
+            }
+        )*
+    };
+}
+
+impl_coordinate!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+macro_rules! impl_vector {
+    ($name:ident { $($field:ident),+ }, $format:literal) => {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+        pub struct $name<T> {
+            $(pub $field: T),+
+        }
+
+        impl<T: Coordinate> $name<T> {
+            pub fn new($($field: T),+) -> Self {
+                Self { $($field),+ }
+            }
+
+            /// Exact square of the euclidean distance, which orders points like the distance itself.
+            ///
+            /// Only overflows when coordinates are more than 2^63 apart.
+            pub fn squared_distance(self, other: Self) -> u128 {
+                0 $(+ self.$field.distance_to(other.$field).pow(2))+
+            }
+
+            pub fn manhattan_distance(self, other: Self) -> u128 {
+                0 $(+ self.$field.distance_to(other.$field))+
+            }
+
+            pub fn chebyshev_distance(self, other: Self) -> u128 {
+                0 $(.max(self.$field.distance_to(other.$field)))+
+            }
+
+            /// The smallest coordinates of both vectors on each axis.
+            pub fn min(self, other: Self) -> Self {
+                Self { $($field: self.$field.min(other.$field)),+ }
+            }
+
+            /// The largest coordinates of both vectors on each axis.
+            pub fn max(self, other: Self) -> Self {
+                Self { $($field: self.$field.max(other.$field)),+ }
+            }
+        }
+
+        impl<T: Coordinate> Add for $name<T> {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                Self { $($field: self.$field + other.$field),+ }
+            }
+        }
+
+        impl<T: Coordinate> Sub for $name<T> {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                Self { $($field: self.$field - other.$field),+ }
+            }
+        }
+
+        impl<T: Coordinate> Mul<T> for $name<T> {
+            type Output = Self;
+
+            fn mul(self, factor: T) -> Self {
+                Self { $($field: self.$field * factor),+ }
+            }
+        }
+
+        impl<T: Coordinate + Neg<Output = T>> Neg for $name<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self { $($field: -self.$field),+ }
+            }
+        }
+
+        impl<T: Coordinate> AddAssign for $name<T> {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl<T: Coordinate> SubAssign for $name<T> {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+
+        impl<T: Display> Display for $name<T> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                write!(f, $format, $(self.$field),+)
+            }
+        }
+
+        impl<T: Coordinate> BoundingBox<$name<T>> {
+            /// Number of integer points inside the box, borders included.
+            pub fn cells(&self) -> u128 {
+                1 $(* (self.min.$field.distance_to(self.max.$field) + 1))+
+            }
+
+            pub fn contains(&self, point: $name<T>) -> bool {
+                $(self.min.$field <= point.$field && point.$field <= self.max.$field)&&+
+            }
+        }
+
+        impl<T: Coordinate> Vector for $name<T> {
+            fn min(self, other: Self) -> Self {
+                $name::min(self, other)
+            }
+
+            fn max(self, other: Self) -> Self {
+                $name::max(self, other)
+            }
+        }
+    };
+}
+
+impl_vector!(Vector2 { x, y }, "({}, {})");
+impl_vector!(Vector3 { x, y, z }, "({}, {}, {})");
+
+/// Operations shared by `Vector2` and `Vector3`.
+pub trait Vector: Copy {
+    fn min(self, other: Self) -> Self;
+    fn max(self, other: Self) -> Self;
+}
+
+/// Axis-aligned box between two corners, both included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BoundingBox<V> {
+    pub min: V,
+    pub max: V,
+}
+
+impl<V: Vector> BoundingBox<V> {
+    /// Smallest box containing every point, `None` without points.
+    pub fn from_points(points: impl IntoIterator<Item = V>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(
+            Self {
+                min: first,
+                max: first,
+            },
+            |bounds, point| bounds.including(point),
+        ))
+    }
+
+    /// Smallest box containing this one and `point`.
+    pub fn including(self, point: V) -> Self {
+        Self {
+            min: self.min.min(point),
+            max: self.max.max(point),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn computes_exact_distances() {
+        let a = Vector3::new(0_usize, 0, 0);
+        let b = Vector3::new(100_000_usize, 3, 4);
+        assert_eq!(10_000_000_025, a.squared_distance(b));
+        assert_eq!(100_007, a.manhattan_distance(b));
+        assert_eq!(100_000, a.chebyshev_distance(b));
+
+        // isqrt would give 1 for both
+        let c = Vector2::new(0_i32, 1);
+        assert!(
+            Vector2::new(0, 0).squared_distance(Vector2::new(1, 1))
+                > Vector2::new(0, 0).squared_distance(c)
+        );
+
+        let far = Vector2::new(i64::MIN, i64::MIN);
+        let near = Vector2::new(i64::MAX, 0);
+        assert_eq!(u64::MAX as u128, far.chebyshev_distance(near));
+    }
+
+    #[test]
+    fn supports_arithmetic() {
+        let mut a = Vector2::new(1, -2);
+        assert_eq!(Vector2::new(4, 2), a + Vector2::new(3, 4));
+        assert_eq!(Vector2::new(-2, -6), a - Vector2::new(3, 4));
+        assert_eq!(Vector2::new(3, -6), a * 3);
+        assert_eq!(Vector2::new(-1, 2), -a);
+
+        a += Vector2::new(1, 1);
+        a -= Vector2::new(0, 2);
+        assert_eq!(Vector2::new(2, -3), a);
+        assert_eq!("(2, -3)", a.to_string());
+    }
+
+    #[test]
+    fn builds_bounding_boxes() {
+        let points = [
+            Vector2::new(7_usize, 1),
+            Vector2::new(2, 5),
+            Vector2::new(11, 3),
+        ];
+        let bounds = BoundingBox::from_points(points).unwrap();
+        assert_eq!(Vector2::new(2, 1), bounds.min);
+        assert_eq!(Vector2::new(11, 5), bounds.max);
+        assert_eq!(50, bounds.cells());
+        assert!(bounds.contains(Vector2::new(2, 5)));
+        assert!(!bounds.contains(Vector2::new(12, 5)));
+        assert_eq!(None, BoundingBox::<Vector3<u8>>::from_points([]));
+    }
+}
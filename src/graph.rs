@@ -0,0 +1,344 @@
+use anyhow::*;
+use std::collections::{HashMap, VecDeque};
+
+pub type NodeId = usize;
+
+/// Graph stored as adjacency lists, with optional node names.
+#[derive(Debug, Clone)]
+pub struct Graph {
+    directed: bool,
+    edges: Vec<Vec<NodeId>>,
+    names: Vec<Option<String>>,
+    ids: HashMap<String, NodeId>,
+}
+
+impl Graph {
+    pub fn directed() -> Self {
+        Self::new(true)
+    }
+
+    pub fn undirected() -> Self {
+        Self::new(false)
+    }
+
+    fn new(directed: bool) -> Self {
+        Self {
+            directed,
+            edges: Vec::new(),
+            names: Vec::new(),
+            ids: HashMap::new(),
+        }
+    }
+
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    pub fn len(&self) -> usize {
+        self.edges.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.edges.is_empty()
+    }
+
+    /// Adds a node without a name.
+    pub fn add_node(&mut self) -> NodeId {
+        self.edges.push(Vec::new());
+        self.names.push(None);
+        self.edges.len() - 1
+    }
+
+    /// The node called `name`, added if it does not exist yet.
+    pub fn intern(&mut self, name: &str) -> NodeId {
+        if let Some(&id) = self.ids.get(name) {
+            return id;
+        }
+        let id = self.add_node();
+        self.names[id] = Some(name.to_string());
+        self.ids.insert(name.to_string(), id);
+        id
+    }
+
+    pub fn id(&self, name: &str) -> Option<NodeId> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: NodeId) -> Option<&str> {
+        self.names.get(id)?.as_deref()
+    }
+
+    /// Panics when `a` or `b` is not a node of the graph.
+    pub fn add_edge(&mut self, a: NodeId, b: NodeId) {
+        assert!(
+            a < self.len() && b < self.len(),
+            "edge {}-{} out of {} nodes",
+            a,
+            b,
+            self.len()
+        );
+        self.edges[a].push(b);
+        if !self.directed && a != b {
+            self.edges[b].push(a);
+        }
+    }
+
+    /// Adds an edge between the nodes called `a` and `b`, adding them if needed.
+    pub fn add_edge_by_name(&mut self, a: &str, b: &str) -> (NodeId, NodeId) {
+        let (a, b) = (self.intern(a), self.intern(b));
+        self.add_edge(a, b);
+        (a, b)
+    }
+
+    pub fn neighbors(&self, node: NodeId) -> &[NodeId] {
+        &self.edges[node]
+    }
+
+    /// Every edge once, as `(from, to)`. Undirected edges are given with `from <= to`.
+    pub fn edges(&self) -> impl Iterator<Item = (NodeId, NodeId)> + '_ {
+        self.edges
+            .iter()
+            .enumerate()
+            .flat_map(move |(a, neighbors)| {
+                neighbors
+                    .iter()
+                    .filter(move |&&b| self.directed || a <= b)
+                    .map(move |&b| (a, b))
+            })
+    }
+
+    /// Nodes of each connected component, ignoring the direction of edges.
+    ///
+    /// Components are ordered by their smallest node, and their nodes in increasing order.
+    pub fn components(&self) -> Vec<Vec<NodeId>> {
+        let mut set = DisjointSet::new(self.len());
+        for (a, b) in self.edges() {
+            set.union(a, b);
+        }
+
+        let mut components: Vec<Vec<NodeId>> = Vec::new();
+        let mut index_of_root = HashMap::new();
+        for node in 0..self.len() {
+            let root = set.find(node);
+            let index = *index_of_root.entry(root).or_insert_with(|| {
+                components.push(Vec::new());
+                components.len() - 1
+            });
+            components[index].push(node);
+        }
+        components
+    }
+
+    /// Nodes ordered so that every edge goes forward, or an error when a directed cycle prevents it.
+    pub fn topological_sort(&self) -> Result<Vec<NodeId>> {
+        ensure!(self.directed, "only directed graphs can be sorted");
+
+        let mut incoming = vec![0; self.len()];
+        for (_, b) in self.edges() {
+            incoming[b] += 1;
+        }
+
+        let mut ready = (0..self.len())
+            .filter(|&node| incoming[node] == 0)
+            .collect::<VecDeque<_>>();
+        let mut order = Vec::with_capacity(self.len());
+        while let Some(node) = ready.pop_front() {
+            order.push(node);
+            for &next in self.neighbors(node) {
+                incoming[next] -= 1;
+                if incoming[next] == 0 {
+                    ready.push_back(next);
+                }
+            }
+        }
+
+        ensure!(order.len() == self.len(), "the graph has a cycle");
+        Ok(order)
+    }
+
+    pub fn has_cycle(&self) -> bool {
+        if self.directed {
+            return self.topological_sort().is_err();
+        }
+
+        // An undirected edge closes a cycle when both ends are already connected
+        let mut set = DisjointSet::new(self.len());
+        self.edges().any(|(a, b)| !set.union(a, b))
+    }
+
+    /// Number of distinct paths from `from` to `to` in a directed acyclic graph.
+    pub fn count_paths(&self, from: NodeId, to: NodeId) -> Result<u128> {
+        let mut counts = vec![0_u128; self.len()];
+        counts[from] = 1;
+        for node in self.topological_sort()? {
+            if counts[node] == 0 || node == to {
+                continue;
+            }
+            for &next in self.neighbors(node) {
+                counts[next] = counts[next]
+                    .checked_add(counts[node])
+                    .ok_or_else(|| anyhow!("too many paths to count"))?;
+            }
+        }
+        Ok(counts[to])
+    }
+}
+
+/// Union-find over `0..n`, with path compression and union by size.
+#[derive(Debug, Clone)]
+pub struct DisjointSet {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    components: usize,
+}
+
+impl DisjointSet {
+    pub fn new(n: usize) -> Self {
+        Self {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+            components: n,
+        }
+    }
+
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+
+        let mut current = x;
+        while self.parent[current] != root {
+            let next = self.parent[current];
+            self.parent[current] = root;
+            current = next;
+        }
+        root
+    }
+
+    /// Merges the sets of `a` and `b`, returning false when they already were the same.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut ra, mut rb) = (self.find(a), self.find(b));
+        if ra == rb {
+            return false;
+        }
+
+        if self.size[ra] < self.size[rb] {
+            std::mem::swap(&mut ra, &mut rb);
+        }
+        self.parent[rb] = ra;
+        self.size[ra] += self.size[rb];
+        self.components -= 1;
+        true
+    }
+
+    /// Size of the set containing `x`.
+    pub fn size_of(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+
+    pub fn component_count(&self) -> usize {
+        self.components
+    }
+
+    /// Size of every set, in no particular order.
+    pub fn component_sizes(&self) -> Vec<usize> {
+        (0..self.parent.len())
+            .filter(|&x| self.parent[x] == x)
+            .map(|root| self.size[root])
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn directed(edges: &[(&str, &str)]) -> Graph {
+        let mut graph = Graph::directed();
+        for (a, b) in edges {
+            graph.add_edge_by_name(a, b);
+        }
+        graph
+    }
+
+    #[test]
+    fn interns_names() {
+        let mut graph = Graph::directed();
+        let (a, b) = graph.add_edge_by_name("a", "b");
+        assert_eq!((a, b), graph.add_edge_by_name("a", "b"));
+        let c = graph.add_node();
+        assert_eq!(3, graph.len());
+        assert_eq!(Some(b), graph.id("b"));
+        assert_eq!(Some("a"), graph.name(a));
+        assert_eq!(None, graph.name(c));
+        assert_eq!(&[b, b], graph.neighbors(a));
+        assert!(graph.neighbors(b).is_empty());
+    }
+
+    #[test]
+    fn finds_components() {
+        let mut graph = Graph::undirected();
+        for _ in 0..6 {
+            graph.add_node();
+        }
+        graph.add_edge(4, 0);
+        graph.add_edge(2, 3);
+        graph.add_edge(3, 5);
+        assert_eq!(vec![vec![0, 4], vec![1], vec![2, 3, 5]], graph.components());
+        assert_eq!(&[0], graph.neighbors(4));
+        assert_eq!(&[4], graph.neighbors(0));
+        assert!(!graph.has_cycle());
+
+        graph.add_edge(5, 2);
+        assert!(graph.has_cycle());
+    }
+
+    #[test]
+    fn sorts_topologically() -> Result<()> {
+        let graph = directed(&[("b", "c"), ("a", "b"), ("a", "c")]);
+        let order = graph.topological_sort()?;
+        let position = |name| order.iter().position(|&n| Some(n) == graph.id(name));
+        assert!(position("a") < position("b") && position("b") < position("c"));
+        assert!(!graph.has_cycle());
+
+        let cyclic = directed(&[("a", "b"), ("b", "c"), ("c", "a")]);
+        assert!(cyclic.topological_sort().is_err());
+        assert!(cyclic.has_cycle());
+        Ok(())
+    }
+
+    #[test]
+    fn counts_paths_in_a_dag() -> Result<()> {
+        let graph = directed(&[
+            ("you", "bbb"),
+            ("you", "ccc"),
+            ("bbb", "out"),
+            ("ccc", "out"),
+            ("ccc", "ddd"),
+            ("ddd", "out"),
+            ("out", "after"),
+        ]);
+        let (you, out) = (graph.id("you").unwrap(), graph.id("out").unwrap());
+        assert_eq!(3, graph.count_paths(you, out)?);
+        assert_eq!(0, graph.count_paths(out, you)?);
+        assert!(directed(&[("a", "a")]).count_paths(0, 0).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn tracks_set_sizes() {
+        let mut set = DisjointSet::new(5);
+        assert!(set.union(0, 1));
+        assert!(set.union(3, 1));
+        assert!(!set.union(0, 3));
+        assert_eq!(3, set.size_of(1));
+        assert_eq!(1, set.size_of(2));
+        assert_eq!(3, set.component_count());
+
+        let mut sizes = set.component_sizes();
+        sizes.sort_unstable();
+        assert_eq!(vec![1, 1, 3], sizes);
+    }
+}
@@ -0,0 +1,339 @@
+use crate::InputError;
+use anyhow::*;
+use std::fmt::{Display, Formatter};
+use std::io::BufRead;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+/// A cell of a grid, as `(row, column)`.
+pub type Position = (usize, usize);
+
+const NEIGHBORS_4: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+const NEIGHBORS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// Rectangular grid stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Builds a grid from its rows, which must all have the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        if let Some((i, row)) = rows.iter().enumerate().find(|(_, r)| r.len() != width) {
+            bail!("row {} has {} cells instead of {}", i + 1, row.len(), width);
+        }
+
+        Ok(Self {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (row, column): Position) -> bool {
+        row < self.height && column < self.width
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.contains(position)
+            .then(|| &self.cells[position.0 * self.width + position.1])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.contains(position)
+            .then(|| &mut self.cells[position.0 * self.width + position.1])
+    }
+
+    /// Panics when `row` is out of the grid.
+    pub fn row(&self, row: usize) -> &[T] {
+        assert!(row < self.height, "row {} out of {}", row, self.height);
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` would yield nothing for rows of width 0
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    /// Panics when `column` is out of the grid.
+    pub fn column(&self, column: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            column < self.width,
+            "column {} out of {}",
+            column,
+            self.width
+        );
+        self.cells.iter().skip(column).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|column| self.column(column))
+    }
+
+    /// The grid flipped over its diagonal, so that its rows are the columns of this one.
+    pub fn transposed(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells: self.columns().flatten().cloned().collect(),
+        }
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |column| (row, column)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn find(&self, value: &T) -> Option<Position>
+    where
+        T: PartialEq,
+    {
+        self.find_all(value).next()
+    }
+
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Position> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(position, _)| position)
+    }
+
+    fn offsets(
+        &self,
+        (row, column): Position,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = Position> + '_ {
+        offsets.iter().filter_map(move |&(dr, dc)| {
+            let neighbor = (row.checked_add_signed(dr)?, column.checked_add_signed(dc)?);
+            self.contains(neighbor).then_some(neighbor)
+        })
+    }
+
+    /// Orthogonal neighbors of `position` that are inside the grid.
+    pub fn neighbors4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        self.offsets(position, &NEIGHBORS_4)
+    }
+
+    /// Orthogonal and diagonal neighbors of `position` that are inside the grid.
+    pub fn neighbors8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        self.offsets(position, &NEIGHBORS_8)
+    }
+}
+
+impl Grid<char> {
+    /// Reads one row per line, skipping empty lines. Lines must all have the same length.
+    pub fn parse(reader: &mut dyn BufRead) -> Result<Self> {
+        let rows = Self::read_rows(reader)?;
+        let width = rows.first().map_or(0, |(_, row)| row.len());
+        if let Some((line, row)) = rows.iter().find(|(_, row)| row.len() != width) {
+            return Err(InputError::new(
+                *line,
+                row.len().min(width) + 1,
+                row.iter().collect::<String>(),
+                format!("expected {} cells, found {}", width, row.len()),
+            )
+            .into());
+        }
+        Self::from_rows(rows.into_iter().map(|(_, row)| row).collect())
+    }
+
+    /// Reads one row per line, skipping empty lines, and pads the shorter lines with `fill`.
+    pub fn parse_padded(reader: &mut dyn BufRead, fill: char) -> Result<Self> {
+        let mut rows = Self::read_rows(reader)?
+            .into_iter()
+            .map(|(_, row)| row)
+            .collect::<Vec<_>>();
+        let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+        for row in &mut rows {
+            row.resize(width, fill);
+        }
+        Self::from_rows(rows)
+    }
+
+    /// The non-empty lines with their 1-based line number.
+    fn read_rows(reader: &mut dyn BufRead) -> Result<Vec<(usize, Vec<char>)>> {
+        let mut rows = Vec::new();
+        for (i, line) in reader.lines().enumerate() {
+            let line = line?;
+            if !line.is_empty() {
+                rows.push((i + 1, line.chars().collect()));
+            }
+        }
+        Ok(rows)
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::parse(&mut s.as_bytes())
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        self.get(position).unwrap_or_else(|| {
+            panic!(
+                "{:?} out of a {}x{} grid",
+                position, self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{:?} out of a {}x{} grid", position, width, height))
+    }
+}
+
+/// Writes one line per row, without a trailing newline.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        std::fmt::Result::Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &str = "\
+ab.
+.#c
+";
+
+    #[test]
+    fn parses_and_displays() -> Result<()> {
+        let grid = TEXT.parse::<Grid<char>>()?;
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!(TEXT.trim_end(), grid.to_string());
+        assert_eq!(grid, grid.to_string().parse()?);
+
+        let error = "ab\n\nc\n".parse::<Grid<char>>().unwrap_err();
+        assert_eq!(
+            "line 3, column 2: expected 2 cells, found 1",
+            error.downcast::<InputError>()?.to_string()
+        );
+        let padded = Grid::parse_padded(&mut "ab\nc\n".as_bytes(), ' ')?;
+        assert_eq!("ab\nc ", padded.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn checks_bounds() -> Result<()> {
+        let mut grid = TEXT.parse::<Grid<char>>()?;
+        assert_eq!(Some(&'#'), grid.get((1, 1)));
+        assert_eq!(None, grid.get((2, 0)));
+        assert_eq!(None, grid.get((0, 3)));
+
+        grid[(0, 2)] = 'x';
+        assert_eq!('x', grid[(0, 2)]);
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "(0, 3) out of a 3x2 grid")]
+    fn panics_out_of_bounds() {
+        let grid = Grid::new(3, 2, '.');
+        let _ = grid[(0, 3)];
+    }
+
+    #[test]
+    fn gives_rows_columns_and_transposed() -> Result<()> {
+        let grid = TEXT.parse::<Grid<char>>()?;
+        assert_eq!(&['.', '#', 'c'], grid.row(1));
+        assert_eq!(vec![&'b', &'#'], grid.column(1).collect::<Vec<_>>());
+        assert_eq!("a.\nb#\n.c", grid.transposed().to_string());
+        assert_eq!(grid, grid.transposed().transposed());
+        Ok(())
+    }
+
+    #[test]
+    fn finds_values() -> Result<()> {
+        let grid = TEXT.parse::<Grid<char>>()?;
+        assert_eq!(
+            vec![(0, 2), (1, 0)],
+            grid.find_all(&'.').collect::<Vec<_>>()
+        );
+        assert_eq!(Some((1, 1)), grid.find(&'#'));
+        assert_eq!(None, grid.find(&'z'));
+        Ok(())
+    }
+
+    #[test]
+    fn iterates_neighbors_inside_the_grid() {
+        let grid = Grid::new(3, 3, 0);
+        assert_eq!(
+            vec![(0, 1), (1, 0)],
+            grid.neighbors4((0, 0)).collect::<Vec<_>>()
+        );
+        assert_eq!(3, grid.neighbors8((0, 0)).count());
+        assert_eq!(5, grid.neighbors8((1, 2)).count());
+        assert_eq!(8, grid.neighbors8((1, 1)).count());
+        assert_eq!(4, grid.neighbors4((1, 1)).count());
+    }
+}
@@ -0,0 +1,429 @@
+use anyhow::*;
+use sha2::{Digest, Sha256};
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::time::Duration;
+
+pub const YEAR: u16 = 2025;
+
+/// Somewhere a day's puzzle input can come from.
+pub trait InputSource: Send + Sync {
+    /// Where this source looks for `day`, as shown in diagnostics.
+    fn describe(&self, day: u8) -> String;
+
+    /// Returns `Ok(None)` when this source has no input for `day`, so the next source is tried.
+    fn load(&self, day: u8) -> Result<Option<String>>;
+}
+
+/// Reads `NN.txt` from a directory, `input/` by default.
+///
+/// An empty file is a placeholder (as created by `aoc new`) and counts as no input.
+pub struct LocalDir {
+    dir: PathBuf,
+}
+
+impl LocalDir {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    pub fn path(&self, day: u8) -> PathBuf {
+        self.dir.join(format!("{:02}.txt", day))
+    }
+}
+
+impl InputSource for LocalDir {
+    fn describe(&self, day: u8) -> String {
+        self.path(day).display().to_string()
+    }
+
+    fn load(&self, day: u8) -> Result<Option<String>> {
+        let path = self.path(day);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let text = fs::read_to_string(&path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        Ok(Some(text).filter(|text| !text.trim().is_empty()))
+    }
+}
+
+/// Reads the file named by `AOC_INPUT_NN` (e.g. `AOC_INPUT_07=/tmp/other.txt`).
+pub struct EnvOverride;
+
+impl EnvOverride {
+    pub fn variable(day: u8) -> String {
+        format!("AOC_INPUT_{:02}", day)
+    }
+}
+
+impl InputSource for EnvOverride {
+    fn describe(&self, day: u8) -> String {
+        format!("${}", Self::variable(day))
+    }
+
+    fn load(&self, day: u8) -> Result<Option<String>> {
+        let Some(path) = std::env::var_os(Self::variable(day)) else {
+            return Ok(None);
+        };
+        let text = fs::read_to_string(&path).with_context(|| {
+            format!(
+                "failed to read {} (from ${})",
+                Path::new(&path).display(),
+                Self::variable(day)
+            )
+        })?;
+        Ok(Some(text))
+    }
+}
+
+/// Reads the whole standard input once and hands it to every day asking for it.
+#[derive(Default)]
+pub struct Stdin {
+    // Days may load it concurrently, so it is read inside the lock and errors are kept as text
+    text: OnceLock<std::result::Result<String, String>>,
+}
+
+impl InputSource for Stdin {
+    fn describe(&self, _day: u8) -> String {
+        "standard input".to_string()
+    }
+
+    fn load(&self, _day: u8) -> Result<Option<String>> {
+        let text = self.text.get_or_init(|| {
+            let mut text = String::new();
+            std::io::stdin()
+                .read_to_string(&mut text)
+                .map(|_| text)
+                .map_err(|e| format!("failed to read standard input: {}", e))
+        });
+        match text {
+            Result::Ok(text) => Ok(Some(text.clone())),
+            Err(e) => bail!("{}", e),
+        }
+    }
+}
+
+/// Content-addressed store for downloaded inputs.
+///
+/// Inputs are stored once under `objects/<sha256>`, and `refs/<year>-<day>` names the object of each day.
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    pub fn hash(text: &str) -> String {
+        Sha256::digest(text.as_bytes())
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect()
+    }
+
+    fn ref_path(&self, key: &str) -> PathBuf {
+        self.dir.join("refs").join(key)
+    }
+
+    fn object_path(&self, hash: &str) -> PathBuf {
+        self.dir.join("objects").join(hash)
+    }
+
+    pub fn get(&self, key: &str) -> Result<Option<String>> {
+        let Result::Ok(hash) = fs::read_to_string(self.ref_path(key)) else {
+            return Ok(None);
+        };
+        let Result::Ok(text) = fs::read_to_string(self.object_path(hash.trim())) else {
+            return Ok(None);
+        };
+
+        // A corrupted object is treated as a cache miss
+        if Self::hash(&text) != hash.trim() {
+            return Ok(None);
+        }
+        Ok(Some(text))
+    }
+
+    /// Stores `text` and points `key` at it, returning its hash.
+    pub fn put(&self, key: &str, text: &str) -> Result<String> {
+        let hash = Self::hash(text);
+        let object = self.object_path(&hash);
+        let reference = self.ref_path(key);
+
+        fs::create_dir_all(object.parent().unwrap())?;
+        fs::create_dir_all(reference.parent().unwrap())?;
+        if !object.exists() {
+            fs::write(&object, text)
+                .with_context(|| format!("failed to write {}", object.display()))?;
+        }
+        fs::write(&reference, &hash)
+            .with_context(|| format!("failed to write {}", reference.display()))?;
+        Ok(hash)
+    }
+}
+
+/// Downloads inputs from the Advent of Code website (or anything serving the same URLs) and caches them.
+pub struct HttpFetcher {
+    base_url: String,
+    session: String,
+    cache: Cache,
+}
+
+impl HttpFetcher {
+    pub const DEFAULT_BASE_URL: &'static str = "https://adventofcode.com";
+
+    pub fn new(base_url: impl Into<String>, session: impl Into<String>, cache: Cache) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            session: session.into(),
+            cache,
+        }
+    }
+
+    /// Uses `AOC_SESSION`, and `AOC_BASE_URL` when set, returning `None` without a session cookie.
+    pub fn from_env(cache: Cache) -> Option<Self> {
+        let session = std::env::var("AOC_SESSION").ok()?;
+        let base_url =
+            std::env::var("AOC_BASE_URL").unwrap_or_else(|_| Self::DEFAULT_BASE_URL.to_string());
+        Some(Self::new(base_url, session, cache))
+    }
+
+    pub fn url(&self, day: u8) -> String {
+        format!("{}/{}/day/{}/input", self.base_url, YEAR, day)
+    }
+
+    fn cache_key(day: u8) -> String {
+        format!("{}-{:02}", YEAR, day)
+    }
+}
+
+impl InputSource for HttpFetcher {
+    fn describe(&self, day: u8) -> String {
+        self.url(day)
+    }
+
+    fn load(&self, day: u8) -> Result<Option<String>> {
+        if let Some(text) = self.cache.get(&Self::cache_key(day))? {
+            return Ok(Some(text));
+        }
+
+        let url = self.url(day);
+        let response = ureq::AgentBuilder::new()
+            .timeout(Duration::from_secs(30))
+            .build()
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set(
+                "User-Agent",
+                "github.com/yoyozbi/advent-of-code-2025 input provider",
+            )
+            .call();
+
+        let text = match response {
+            Result::Ok(response) => response.into_string()?,
+            Err(ureq::Error::Status(404, _)) => return Ok(None),
+            Err(ureq::Error::Status(code, _)) => {
+                bail!(
+                    "{} answered HTTP {} (is AOC_SESSION still valid?)",
+                    url,
+                    code
+                )
+            }
+            Err(e) => return Err(e).with_context(|| format!("failed to download {}", url)),
+        };
+
+        self.cache.put(&Self::cache_key(day), &text)?;
+        Ok(Some(text))
+    }
+}
+
+/// Raised when no source has an input for a day, listing where it looked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingInput {
+    pub day: u8,
+    pub tried: Vec<String>,
+}
+
+impl Display for MissingInput {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "no puzzle input found for day {:02}, tried:", self.day)?;
+        for tried in &self.tried {
+            writeln!(f, "  - {}", tried)?;
+        }
+        write!(
+            f,
+            "save the input to input/{:02}.txt, or set AOC_SESSION to download it",
+            self.day
+        )
+    }
+}
+
+impl std::error::Error for MissingInput {}
+
+/// An ordered list of [`InputSource`]s; the first one with an input for the day wins.
+#[derive(Default)]
+pub struct InputProvider {
+    sources: Vec<Box<dyn InputSource>>,
+}
+
+impl InputProvider {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_source(mut self, source: impl InputSource + 'static) -> Self {
+        self.sources.push(Box::new(source));
+        self
+    }
+
+    /// `$AOC_INPUT_NN`, then `input/NN.txt`, then the website when `AOC_SESSION` is set.
+    pub fn from_env() -> Self {
+        let provider = Self::new()
+            .with_source(EnvOverride)
+            .with_source(LocalDir::new("input"));
+
+        match HttpFetcher::from_env(Cache::new("input/.cache")) {
+            Some(fetcher) => provider.with_source(fetcher),
+            None => provider,
+        }
+    }
+
+    pub fn load(&self, day: u8) -> Result<String> {
+        for source in &self.sources {
+            if let Some(text) = source.load(day)? {
+                return Ok(text);
+            }
+        }
+
+        Err(MissingInput {
+            day,
+            tried: self.sources.iter().map(|s| s.describe(day)).collect(),
+        }
+        .into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-input-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    /// Serves `body` with `status` to every request, returning the base URL.
+    fn mock_server(status: &'static str, body: &'static str) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut line = String::new();
+                while reader.read_line(&mut line).unwrap() > 2 {
+                    line.clear();
+                }
+                write!(
+                    stream,
+                    "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+        });
+        format!("http://{}", address)
+    }
+
+    #[test]
+    fn first_source_with_an_input_wins() -> Result<()> {
+        let first = temp_dir("first");
+        let second = temp_dir("second");
+        fs::write(second.join("03.txt"), "second")?;
+        fs::write(second.join("04.txt"), "fallback")?;
+        fs::write(first.join("03.txt"), "first")?;
+        fs::write(first.join("04.txt"), "\n")?;
+
+        let provider = InputProvider::new()
+            .with_source(LocalDir::new(&first))
+            .with_source(LocalDir::new(&second));
+        assert_eq!("first", provider.load(3)?);
+        assert_eq!("fallback", provider.load(4)?);
+        Ok(())
+    }
+
+    #[test]
+    fn missing_input_lists_every_source() {
+        let dir = temp_dir("missing");
+        let error = InputProvider::new()
+            .with_source(EnvOverride)
+            .with_source(LocalDir::new(&dir))
+            .load(9)
+            .unwrap_err();
+
+        let missing = error.downcast_ref::<MissingInput>().unwrap();
+        assert_eq!(9, missing.day);
+        assert_eq!("$AOC_INPUT_09", missing.tried[0]);
+        assert!(missing.tried[1].ends_with("09.txt"));
+        assert!(error.to_string().contains("input/09.txt"));
+    }
+
+    #[test]
+    fn cache_is_content_addressed() -> Result<()> {
+        let cache = Cache::new(temp_dir("cache"));
+        assert_eq!(None, cache.get("2025-01")?);
+
+        let hash = cache.put("2025-01", "L68\n")?;
+        assert_eq!(hash, cache.put("2025-02", "L68\n")?);
+        assert_eq!(Some("L68\n".to_string()), cache.get("2025-02")?);
+
+        fs::write(cache.object_path(&hash), "tampered")?;
+        assert_eq!(None, cache.get("2025-01")?);
+        Ok(())
+    }
+
+    #[test]
+    fn fetches_and_caches_from_a_server() -> Result<()> {
+        let dir = temp_dir("http");
+        let fetcher = HttpFetcher::new(mock_server("200 OK", "1,2\n"), "secret", Cache::new(&dir));
+        assert_eq!(Some("1,2\n".to_string()), fetcher.load(9)?);
+
+        // The second load is served from the cache, even with the server gone
+        let offline = HttpFetcher::new("http://127.0.0.1:9", "secret", Cache::new(&dir));
+        assert_eq!(Some("1,2\n".to_string()), offline.load(9)?);
+        Ok(())
+    }
+
+    #[test]
+    fn reports_server_errors() -> Result<()> {
+        let missing = HttpFetcher::new(
+            mock_server("404 Not Found", ""),
+            "secret",
+            Cache::new(temp_dir("http404")),
+        );
+        assert_eq!(None, missing.load(1)?);
+
+        let expired = HttpFetcher::new(
+            mock_server("400 Bad Request", "expired"),
+            "secret",
+            Cache::new(temp_dir("http400")),
+        );
+        assert!(expired
+            .load(1)
+            .unwrap_err()
+            .to_string()
+            .contains("AOC_SESSION"));
+        Ok(())
+    }
+}
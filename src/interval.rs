@@ -0,0 +1,275 @@
+use std::fmt::{Debug, Display, Formatter};
+use std::ops::RangeInclusive;
+
+/// Integer type usable as the bounds of an `IntervalSet`.
+pub trait Discrete: Copy + Ord + Debug {
+    fn next(self) -> Option<Self>;
+    fn prev(self) -> Option<Self>;
+    /// Number of values in `start..=end`, with `start <= end`.
+    fn count(start: Self, end: Self) -> u128;
+}
+
+macro_rules! impl_discrete {
+    ($($t:ty),*) => {
+        $(
+            impl Discrete for $t {
+                fn next(self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                fn prev(self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+
+                fn count(start: Self, end: Self) -> u128 {
+                    end.abs_diff(start) as u128 + 1
+                }
+            }
+        )*
+    };
+}
+
+impl_discrete!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+/// Set of integers stored as sorted, disjoint and non-adjacent inclusive ranges.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    ranges: Vec<(T, T)>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self { ranges: Vec::new() }
+    }
+}
+
+impl<T: Discrete> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds every value of `range`, merging it with the ranges it overlaps or touches.
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        // Ranges ending before `start - 1` and starting after `end + 1` are kept as they are
+        let first = self
+            .ranges
+            .partition_point(|&(_, e)| e.next().is_some_and(|next| next < start));
+        let last = self
+            .ranges
+            .partition_point(|&(s, _)| end.next().is_none_or(|next| s <= next));
+        if first < last {
+            start = start.min(self.ranges[first].0);
+            end = end.max(self.ranges[last - 1].1);
+        }
+        self.ranges.splice(first..last, [(start, end)]);
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let i = self.ranges.partition_point(|&(_, end)| end < value);
+        self.ranges.get(i).is_some_and(|&(start, _)| start <= value)
+    }
+
+    /// Number of values in the set.
+    pub fn len(&self) -> u128 {
+        self.ranges
+            .iter()
+            .map(|&(start, end)| T::count(start, end))
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The disjoint ranges of the set, in increasing order.
+    pub fn ranges(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges.iter().map(|&(start, end)| start..=end)
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        result.extend(other.ranges());
+        result
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while let (Some(&(a_start, a_end)), Some(&(b_start, b_end))) =
+            (self.ranges.get(i), other.ranges.get(j))
+        {
+            let (start, end) = (a_start.max(b_start), a_end.min(b_end));
+            if start <= end {
+                ranges.push((start, end));
+            }
+            if a_end < b_end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { ranges }
+    }
+
+    /// Values of this set that are not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let mut j = 0;
+        for &(start, end) in &self.ranges {
+            // Ranges of `other` ending before this one can't remove anything from the next ones either
+            while other.ranges.get(j).is_some_and(|&(_, e)| e < start) {
+                j += 1;
+            }
+
+            // `start` becomes `None` once the rest of the range is removed
+            let mut start = Some(start);
+
+            let mut k = j;
+            while let (Some(s), Some(&(o_start, o_end))) = (start, other.ranges.get(k)) {
+                if o_start > end {
+                    break;
+                }
+                if s < o_start {
+                    ranges.push((s, o_start.prev().unwrap()));
+                }
+                start = o_end.next().filter(|&next| next <= end);
+                k += 1;
+            }
+            if let Some(s) = start {
+                ranges.push((s, end));
+            }
+        }
+        Self { ranges }
+    }
+}
+
+impl<T: Discrete> Extend<RangeInclusive<T>> for IntervalSet<T> {
+    /// Sorts the new ranges with the current ones and merges them in a single pass.
+    fn extend<I: IntoIterator<Item = RangeInclusive<T>>>(&mut self, ranges: I) {
+        let mut all = std::mem::take(&mut self.ranges);
+        all.extend(
+            ranges
+                .into_iter()
+                .map(RangeInclusive::into_inner)
+                .filter(|&(start, end)| start <= end),
+        );
+        all.sort_unstable();
+
+        for (start, end) in all {
+            match self.ranges.last_mut() {
+                Some((_, last)) if last.next().is_none_or(|next| start <= next) => {
+                    *last = end.max(*last);
+                }
+                _ => self.ranges.push((start, end)),
+            }
+        }
+    }
+}
+
+impl<T: Discrete> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(ranges: I) -> Self {
+        let mut set = Self::new();
+        set.extend(ranges);
+        set
+    }
+}
+
+/// Writes the ranges as `3-5,10-20`.
+impl<T: Display> Display for IntervalSet<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (i, (start, end)) in self.ranges.iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{}-{}", start, end)?;
+        }
+        std::fmt::Result::Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[(i64, i64)]) -> IntervalSet<i64> {
+        ranges.iter().map(|&(start, end)| start..=end).collect()
+    }
+
+    #[test]
+    fn merges_overlapping_and_adjacent_ranges() {
+        let ranges = set(&[(10, 14), (3, 5), (16, 20), (12, 18), (21, 21), (30, 29)]);
+        assert_eq!("3-5,10-21", ranges.to_string());
+        assert_eq!(15, ranges.len());
+
+        let mut ranges = set(&[(1, 2), (6, 7), (10, 12)]);
+        ranges.insert(3..=9);
+        assert_eq!("1-12", ranges.to_string());
+        ranges.insert(-5..=-5);
+        assert_eq!("-5--5,1-12", ranges.to_string());
+    }
+
+    #[test]
+    fn builds_from_unsorted_overlapping_ranges() {
+        let ranges = set(&[
+            (40, 45),
+            (1, 3),
+            (20, 30),
+            (2, 8),
+            (9, 9),
+            (25, 41),
+            (-3, -2),
+        ]);
+        assert_eq!("-3--2,1-9,20-45", ranges.to_string());
+
+        let mut inserted = IntervalSet::new();
+        for range in [40..=45, 1..=3, 20..=30, 2..=8, 9..=9, 25..=41, -3..=-2] {
+            inserted.insert(range);
+        }
+        assert_eq!(inserted, ranges);
+
+        let mut extended = set(&[(0, 0), (50, 60)]);
+        extended.extend([55..=70, 12..=18, 1..=4]);
+        assert_eq!("0-4,12-18,50-70", extended.to_string());
+    }
+
+    #[test]
+    fn handles_the_type_bounds() {
+        let ranges: IntervalSet<u8> = [250..=255, 0..=3, 4..=4].into_iter().collect();
+        assert_eq!("0-4,250-255", ranges.to_string());
+        assert_eq!(11, ranges.len());
+        assert!(ranges.contains(255));
+        assert_eq!(
+            "0-0,255-255",
+            ranges
+                .difference(&[1..=254].into_iter().collect())
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn checks_membership() {
+        let ranges = set(&[(3, 5), (10, 20)]);
+        let members = (0..=22).filter(|&v| ranges.contains(v)).count();
+        assert_eq!(14, members);
+        assert!(!ranges.contains(6));
+        assert!(ranges.contains(10));
+        assert!(!IntervalSet::<i64>::new().contains(0));
+    }
+
+    #[test]
+    fn combines_sets() {
+        let a = set(&[(0, 10), (20, 30)]);
+        let b = set(&[(5, 25), (28, 40)]);
+        assert_eq!("0-40", a.union(&b).to_string());
+        assert_eq!("5-10,20-25,28-30", a.intersection(&b).to_string());
+        assert_eq!("0-4,26-27", a.difference(&b).to_string());
+        assert_eq!("11-19,31-40", b.difference(&a).to_string());
+        assert_eq!(a, a.difference(&IntervalSet::new()));
+        assert!(a.difference(&a).is_empty());
+    }
+}
@@ -1,5 +1,39 @@
+mod answer;
+mod answers;
+pub mod days;
+pub mod dial;
+pub mod digits;
+mod error;
+mod example;
+mod geometry;
+mod graph;
+mod grid;
+mod input;
+mod interval;
+pub mod log;
+mod params;
+pub mod parse;
+pub mod render;
+mod solution;
+
+pub use answer::Answer;
+pub use answers::{AnswerSheet, Expected};
+pub use error::InputError;
+pub use example::Example;
+pub use geometry::*;
+pub use graph::{DisjointSet, Graph, NodeId};
+pub use grid::{Grid, Position};
+pub use input::*;
+pub use interval::{Discrete, IntervalSet};
+pub use params::*;
+pub use solution::*;
+
 pub fn start_day(day: &str) {
-    println!("Advent of Code 2025 - Day {:0>2}", day);
+    println!("{}", day_title(day));
+}
+
+pub fn day_title(day: &str) -> String {
+    format!("Advent of Code 2025 - Day {:0>2}", day)
 }
 
 // Additional common functions
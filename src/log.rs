@@ -0,0 +1,114 @@
+//! Diagnostics for solutions, written to standard error and off by default.
+//!
+//! `debug!` and `trace!` only check an atomic when logging is disabled, without
+//! evaluating their arguments, so they can stay in hot loops.
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Debug = 1,
+    Trace = 2,
+}
+
+impl Level {
+    pub fn name(self) -> &'static str {
+        match self {
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        }
+    }
+}
+
+const OFF: u8 = 0;
+
+/// Enabled level of each day, index 0 being used by code outside of a day module.
+static LEVELS: [AtomicU8; 26] = [const { AtomicU8::new(OFF) }; 26];
+
+/// Highest enabled level of any day, so that disabled logging costs a single load.
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(OFF);
+
+/// Enables `level` and below for every day.
+pub fn set_level(level: Level) {
+    for day in &LEVELS {
+        day.fetch_max(level as u8, Ordering::Relaxed);
+    }
+    MAX_LEVEL.fetch_max(level as u8, Ordering::Relaxed);
+}
+
+/// Enables `level` and below for `day` only.
+pub fn set_day_level(day: u8, level: Level) {
+    if let Some(current) = LEVELS.get(day as usize) {
+        current.fetch_max(level as u8, Ordering::Relaxed);
+        MAX_LEVEL.fetch_max(level as u8, Ordering::Relaxed);
+    }
+}
+
+/// Day of a `days::dayNN` module path, 0 for any other module.
+pub(crate) fn day_of(module: &str) -> usize {
+    module
+        .rsplit("::")
+        .next()
+        .and_then(|name| name.strip_prefix("day"))
+        .and_then(|day| day.parse::<usize>().ok())
+        .filter(|&day| day < LEVELS.len())
+        .unwrap_or(0)
+}
+
+#[inline]
+pub fn enabled(level: Level, module: &str) -> bool {
+    level as u8 <= MAX_LEVEL.load(Ordering::Relaxed)
+        && level as u8 <= LEVELS[day_of(module)].load(Ordering::Relaxed)
+}
+
+#[doc(hidden)]
+pub fn write(level: Level, module: &str, args: std::fmt::Arguments) {
+    let name = module.rsplit("::").next().unwrap_or(module);
+    eprintln!("[{} {}] {}", name, level.name(), args);
+}
+
+/// Logs a diagnostic shown by `aoc --verbose`.
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Debug, module_path!()) {
+            $crate::log::write($crate::log::Level::Debug, module_path!(), format_args!($($arg)*));
+        }
+    };
+}
+
+/// Logs a detailed diagnostic, shown by `aoc --trace <day>` for the day it is in.
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Trace, module_path!()) {
+            $crate::log::write($crate::log::Level::Trace, module_path!(), format_args!($($arg)*));
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_the_day_of_a_module() {
+        assert_eq!(7, day_of("adv_code_2025::days::day07"));
+        assert_eq!(12, day_of("adv_code_2025::days::day12"));
+        assert_eq!(0, day_of("adv_code_2025::days"));
+        assert_eq!(0, day_of("adv_code_2025::days::day99"));
+        assert_eq!(0, day_of("aoc"));
+    }
+
+    #[test]
+    fn enables_levels_per_day() {
+        // Day 25 is not used anywhere else, since levels are global
+        let module = "adv_code_2025::days::day25";
+        assert!(!enabled(Level::Debug, module));
+
+        set_day_level(25, Level::Trace);
+        assert!(enabled(Level::Debug, module));
+        assert!(enabled(Level::Trace, module));
+        assert!(!enabled(Level::Trace, "adv_code_2025::days::day24"));
+    }
+}
@@ -0,0 +1,269 @@
+use anyhow::*;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+
+/// The value of a puzzle parameter; its variant is the parameter's type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParamValue {
+    Int(i64),
+    Bool(bool),
+    Text(String),
+}
+
+impl ParamValue {
+    fn type_name(&self) -> &'static str {
+        match self {
+            ParamValue::Int(_) => "integer",
+            ParamValue::Bool(_) => "boolean",
+            ParamValue::Text(_) => "text",
+        }
+    }
+
+    /// Parses `raw` as a value of the same type as `self`.
+    fn parse_like(&self, raw: &str) -> Result<ParamValue> {
+        Ok(match self {
+            ParamValue::Int(_) => ParamValue::Int(
+                raw.trim()
+                    .replace('_', "")
+                    .parse()
+                    .map_err(|_| anyhow!("'{}' is not an integer", raw))?,
+            ),
+            ParamValue::Bool(_) => ParamValue::Bool(
+                raw.trim()
+                    .parse()
+                    .map_err(|_| anyhow!("'{}' is not true or false", raw))?,
+            ),
+            ParamValue::Text(_) => ParamValue::Text(raw.to_string()),
+        })
+    }
+}
+
+impl Display for ParamValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParamValue::Int(value) => write!(f, "{}", value),
+            ParamValue::Bool(value) => write!(f, "{}", value),
+            ParamValue::Text(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+macro_rules! impl_int_param {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for ParamValue {
+                fn from(value: $t) -> Self {
+                    ParamValue::Int(value as i64)
+                }
+            }
+
+            impl FromParam for $t {
+                fn from_param(value: &ParamValue) -> Option<Self> {
+                    match value {
+                        ParamValue::Int(value) => <$t>::try_from(*value).ok(),
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+/// Types a [`ParamValue`] can be read as with [`Params::get`].
+pub trait FromParam: Sized {
+    fn from_param(value: &ParamValue) -> Option<Self>;
+}
+
+impl_int_param!(i32, i64, u32, u64, usize);
+
+impl From<bool> for ParamValue {
+    fn from(value: bool) -> Self {
+        ParamValue::Bool(value)
+    }
+}
+
+impl FromParam for bool {
+    fn from_param(value: &ParamValue) -> Option<Self> {
+        match value {
+            ParamValue::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+}
+
+impl From<&str> for ParamValue {
+    fn from(value: &str) -> Self {
+        ParamValue::Text(value.to_string())
+    }
+}
+
+impl FromParam for String {
+    fn from_param(value: &ParamValue) -> Option<Self> {
+        match value {
+            ParamValue::Text(value) => Some(value.clone()),
+            _ => None,
+        }
+    }
+}
+
+/// A constant a solution declares so it can differ between the example and the real input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Param {
+    pub name: &'static str,
+    pub default: ParamValue,
+    pub description: &'static str,
+}
+
+impl Param {
+    pub fn new(
+        name: &'static str,
+        default: impl Into<ParamValue>,
+        description: &'static str,
+    ) -> Self {
+        Self {
+            name,
+            default: default.into(),
+            description,
+        }
+    }
+}
+
+/// The resolved parameters a part is solved with: the declared defaults, then any overrides.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params {
+    values: BTreeMap<&'static str, ParamValue>,
+}
+
+impl Params {
+    pub fn new(declared: &[Param]) -> Self {
+        Self {
+            values: declared
+                .iter()
+                .map(|param| (param.name, param.default.clone()))
+                .collect(),
+        }
+    }
+
+    pub fn is_declared(&self, name: &str) -> bool {
+        self.values.contains_key(name)
+    }
+
+    fn slot(&mut self, name: &str) -> Result<&mut ParamValue> {
+        let declared = self.values.keys().copied().collect::<Vec<_>>().join(", ");
+        self.values.get_mut(name).ok_or_else(|| {
+            if declared.is_empty() {
+                anyhow!("unknown parameter '{}', this day declares none", name)
+            } else {
+                anyhow!(
+                    "unknown parameter '{}', expected one of: {}",
+                    name,
+                    declared
+                )
+            }
+        })
+    }
+
+    /// Overrides `name`, which must be declared with the same type.
+    pub fn set(&mut self, name: &str, value: impl Into<ParamValue>) -> Result<()> {
+        let value = value.into();
+        let slot = self.slot(name)?;
+        ensure!(
+            std::mem::discriminant(slot) == std::mem::discriminant(&value),
+            "parameter '{}' is {}, got {} '{}'",
+            name,
+            slot.type_name(),
+            value.type_name(),
+            value
+        );
+        *slot = value;
+        Ok(())
+    }
+
+    /// Overrides `name` from its textual form, as given on the command line.
+    pub fn set_str(&mut self, name: &str, raw: &str) -> Result<()> {
+        let slot = self.slot(name)?;
+        *slot = slot
+            .parse_like(raw)
+            .with_context(|| format!("invalid value for parameter '{}'", name))?;
+        Ok(())
+    }
+
+    pub fn get<T: FromParam>(&self, name: &str) -> Result<T> {
+        let value = self
+            .values
+            .get(name)
+            .ok_or_else(|| anyhow!("parameter '{}' is not declared", name))?;
+        T::from_param(value).ok_or_else(|| {
+            anyhow!(
+                "parameter '{}' = {} does not fit a {}",
+                name,
+                value,
+                std::any::type_name::<T>()
+            )
+        })
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &ParamValue)> {
+        self.values.iter().map(|(name, value)| (*name, value))
+    }
+}
+
+impl Display for Params {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let values = self
+            .iter()
+            .map(|(name, value)| format!("{}={}", name, value))
+            .collect::<Vec<_>>();
+        write!(f, "{}", values.join(" "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn declared() -> Vec<Param> {
+        vec![
+            Param::new("connect", 1_000, "pairs to connect"),
+            Param::new("verbose", false, "print more"),
+            Param::new("target", "out", "last node"),
+        ]
+    }
+
+    #[test]
+    fn starts_from_the_defaults() -> Result<()> {
+        let params = Params::new(&declared());
+        assert_eq!(1_000, params.get::<usize>("connect")?);
+        assert!(!params.get::<bool>("verbose")?);
+        assert_eq!("out", params.get::<String>("target")?);
+        assert_eq!("connect=1000 target=out verbose=false", params.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn overrides_keep_the_declared_type() -> Result<()> {
+        let mut params = Params::new(&declared());
+        params.set("connect", 10)?;
+        params.set_str("verbose", "true")?;
+        params.set_str("target", "dac")?;
+        assert_eq!(10, params.get::<u32>("connect")?);
+        assert!(params.get::<bool>("verbose")?);
+        assert_eq!("dac", params.get::<String>("target")?);
+
+        assert!(params.set("connect", "ten").is_err());
+        assert!(params.set_str("connect", "ten").is_err());
+        assert!(params.set_str("missing", "1").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn reading_checks_the_range() -> Result<()> {
+        let mut params = Params::new(&declared());
+        params.set_str("connect", "-1")?;
+        assert_eq!(-1, params.get::<i64>("connect")?);
+        assert!(params.get::<usize>("connect").is_err());
+        assert!(params.get::<bool>("connect").is_err());
+        assert!(params.get::<i64>("missing").is_err());
+        Ok(())
+    }
+}
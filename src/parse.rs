@@ -0,0 +1,367 @@
+//! Small composable parsers for puzzle inputs.
+//!
+//! A parser is any `Fn(&mut Cursor) -> Result<T>`. Failures are `InputError`s
+//! carrying the line and column they happened at, wrapped in `anyhow::Error`.
+
+use crate::InputError;
+use anyhow::*;
+use std::fmt::Display;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+/// Position in one line of the input.
+#[derive(Debug, Clone)]
+pub struct Cursor<'a> {
+    line: usize,
+    text: &'a str,
+    position: usize,
+}
+
+impl<'a> Cursor<'a> {
+    /// A cursor at the start of `text`, which is line `line` (1-based) of the input.
+    pub fn new(line: usize, text: &'a str) -> Self {
+        Self {
+            line,
+            text,
+            position: 0,
+        }
+    }
+
+    pub fn rest(&self) -> &'a str {
+        &self.text[self.position..]
+    }
+
+    pub fn is_at_end(&self) -> bool {
+        self.position == self.text.len()
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    /// An error at the current position.
+    pub fn error(&self, message: impl Display) -> Error {
+        self.error_at(self.position, message)
+    }
+
+    fn error_at(&self, position: usize, message: impl Display) -> Error {
+        InputError::new(
+            self.line,
+            self.text[..position].chars().count() + 1,
+            self.text,
+            message,
+        )
+        .into()
+    }
+
+    /// Describes what is at the current position, for error messages.
+    fn found(&self) -> String {
+        match self.peek() {
+            Some(c) => format!("'{}'", c),
+            None => "end of line".to_string(),
+        }
+    }
+
+    pub fn take_while(&mut self, mut f: impl FnMut(char) -> bool) -> &'a str {
+        let rest = self.rest();
+        let length = rest.find(|c| !f(c)).unwrap_or(rest.len());
+        self.position += length;
+        &rest[..length]
+    }
+
+    pub fn skip_spaces(&mut self) {
+        self.take_while(|c| c == ' ' || c == '\t');
+    }
+
+    /// Consumes `literal` if the rest starts with it.
+    pub fn eat(&mut self, literal: &str) -> bool {
+        let found = self.rest().starts_with(literal);
+        if found {
+            self.position += literal.len();
+        }
+        found
+    }
+
+    pub fn literal(&mut self, literal: &str) -> Result<()> {
+        if self.eat(literal) {
+            Ok(())
+        } else {
+            Err(self.error(format!("expected '{}', found {}", literal, self.found())))
+        }
+    }
+
+    pub fn one_of(&mut self, chars: &[char]) -> Result<char> {
+        match self.peek() {
+            Some(c) if chars.contains(&c) => {
+                self.position += c.len_utf8();
+                Ok(c)
+            }
+            _ => Err(self.error(format!(
+                "expected one of {}, found {}",
+                chars
+                    .iter()
+                    .map(|c| format!("'{}'", c))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                self.found()
+            ))),
+        }
+    }
+
+    /// An optionally signed decimal integer.
+    pub fn int<T: FromStr>(&mut self) -> Result<T>
+    where
+        T::Err: Display,
+    {
+        let start = self.position;
+        let sign = self.take_while(|c| c == '-' || c == '+').len();
+        let digits = self.take_while(|c| c.is_ascii_digit());
+        if sign > 1 || digits.is_empty() {
+            self.position = start;
+            return Err(self.error(format!("expected an integer, found {}", self.found())));
+        }
+
+        self.text[start..self.position]
+            .parse()
+            .map_err(|e| self.error_at(start, e))
+    }
+
+    /// A non-empty run of characters other than spaces.
+    pub fn word(&mut self) -> Result<&'a str> {
+        let word = self.take_while(|c| !c.is_whitespace());
+        if word.is_empty() {
+            return Err(self.error(format!("expected a word, found {}", self.found())));
+        }
+        Ok(word)
+    }
+
+    /// Fails unless the whole line was consumed.
+    pub fn end(&self) -> Result<()> {
+        if self.is_at_end() {
+            Ok(())
+        } else {
+            Err(self.error(format!("unexpected {}", self.found())))
+        }
+    }
+}
+
+/// Parses an integer.
+pub fn int<T: FromStr>(cursor: &mut Cursor) -> Result<T>
+where
+    T::Err: Display,
+{
+    cursor.int()
+}
+
+/// Parses `item`s separated by `separator`, at least one.
+pub fn separated<'s, T>(
+    item: impl Fn(&mut Cursor) -> Result<T> + 's,
+    separator: &'s str,
+) -> impl Fn(&mut Cursor) -> Result<Vec<T>> + 's {
+    move |cursor| {
+        let mut items = vec![item(cursor)?];
+        while cursor.eat(separator) {
+            items.push(item(cursor)?);
+        }
+        Ok(items)
+    }
+}
+
+/// Parses `item`s separated by runs of spaces, at least one. Leading and trailing spaces are skipped.
+pub fn spaced<T>(
+    item: impl Fn(&mut Cursor) -> Result<T>,
+) -> impl Fn(&mut Cursor) -> Result<Vec<T>> {
+    move |cursor| {
+        cursor.skip_spaces();
+        let mut items = vec![item(cursor)?];
+        loop {
+            cursor.skip_spaces();
+            if cursor.is_at_end() {
+                return Ok(items);
+            }
+            items.push(item(cursor)?);
+        }
+    }
+}
+
+/// Parses `first`, `separator` then `second`.
+pub fn pair<'s, A, B>(
+    first: impl Fn(&mut Cursor) -> Result<A> + 's,
+    separator: &'s str,
+    second: impl Fn(&mut Cursor) -> Result<B> + 's,
+) -> impl Fn(&mut Cursor) -> Result<(A, B)> + 's {
+    move |cursor| {
+        let a = first(cursor)?;
+        cursor.literal(separator)?;
+        Ok((a, second(cursor)?))
+    }
+}
+
+/// Parses an inclusive range of integers such as `3-5`, whose end is not below its start.
+pub fn range<T: FromStr + PartialOrd + Display>(cursor: &mut Cursor) -> Result<RangeInclusive<T>>
+where
+    T::Err: Display,
+{
+    let start = cursor.int()?;
+    cursor.literal("-")?;
+    let before = cursor.clone();
+    let end = cursor.int()?;
+    if end < start {
+        return Err(before.error(format!("the range ends before its start {}", start)));
+    }
+    Ok(start..=end)
+}
+
+/// Parses the whole of `text`, which is line `line` (1-based) of the input.
+pub fn line<T>(line: usize, text: &str, parser: impl Fn(&mut Cursor) -> Result<T>) -> Result<T> {
+    let mut cursor = Cursor::new(line, text);
+    let value = parser(&mut cursor)?;
+    cursor.end()?;
+    Ok(value)
+}
+
+/// Parses every non-empty line of `text` with `parser`.
+pub fn lines<T>(text: &str, parser: impl Fn(&mut Cursor) -> Result<T>) -> Result<Vec<T>> {
+    text.lines()
+        .enumerate()
+        .filter(|(_, l)| !l.is_empty())
+        .map(|(i, l)| line(i + 1, l, &parser))
+        .collect()
+}
+
+/// Consecutive non-empty lines of the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Block<'a> {
+    /// 1-based line number of the first line of the block.
+    pub first_line: usize,
+    pub lines: Vec<&'a str>,
+}
+
+impl Block<'_> {
+    /// Parses every line of the block with `parser`.
+    pub fn parse_lines<T>(&self, parser: impl Fn(&mut Cursor) -> Result<T>) -> Result<Vec<T>> {
+        self.lines
+            .iter()
+            .enumerate()
+            .map(|(i, l)| line(self.first_line + i, l, &parser))
+            .collect()
+    }
+}
+
+/// Splits `text` on blank lines.
+pub fn blocks(text: &str) -> Vec<Block<'_>> {
+    let mut blocks: Vec<Block> = Vec::new();
+    let mut in_block = false;
+    for (i, l) in text.lines().enumerate() {
+        if l.trim().is_empty() {
+            in_block = false;
+        } else if in_block {
+            blocks.last_mut().unwrap().lines.push(l);
+        } else {
+            in_block = true;
+            blocks.push(Block {
+                first_line: i + 1,
+                lines: vec![l],
+            });
+        }
+    }
+    blocks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_error(error: Error) -> InputError {
+        error.downcast::<InputError>().unwrap()
+    }
+
+    #[test]
+    fn parses_integers() -> Result<()> {
+        assert_eq!(-12, line(1, "-12", int::<i32>)?);
+        assert_eq!(7, line(1, "+7", int::<u64>)?);
+
+        let error = parse_error(line(3, "ab", int::<i32>).unwrap_err());
+        assert_eq!((3, 1), (error.line, error.column));
+        assert_eq!("ab", error.text);
+        assert_eq!(
+            "line 3, column 1: expected an integer, found 'a'",
+            error.to_string()
+        );
+
+        let error = parse_error(line(1, "1 300", separated(int::<u8>, " ")).unwrap_err());
+        assert_eq!(3, error.column);
+        Ok(())
+    }
+
+    #[test]
+    fn parses_lists_pairs_and_ranges() -> Result<()> {
+        assert_eq!(vec![1, 2, 3], line(1, "1,2,3", separated(int::<i32>, ","))?);
+        assert_eq!(vec![4, 5], line(1, " 4   5 ", spaced(int::<i32>))?);
+        assert_eq!(
+            (7, 1),
+            line(1, "7,1", pair(int::<usize>, ",", int::<usize>))?
+        );
+        assert_eq!(
+            vec![11..=22, 95..=115],
+            line(1, "11-22,95-115", separated(range::<u64>, ","))?
+        );
+        assert_eq!(4..=4, line(1, "4-4", range::<u64>)?);
+        let error = parse_error(line(1, "5-3", range::<u64>).unwrap_err());
+        assert_eq!(
+            (3, "the range ends before its start 5"),
+            (error.column, error.message.as_str())
+        );
+
+        let error = parse_error(line(1, "7;1", pair(int::<usize>, ",", int::<usize>)).unwrap_err());
+        assert_eq!(
+            "line 1, column 2: expected ',', found ';'",
+            error.to_string()
+        );
+        let error = parse_error(line(1, "1,2 x", separated(int::<i32>, ",")).unwrap_err());
+        assert_eq!("line 1, column 4: unexpected ' '", error.to_string());
+        let error = parse_error(line(1, "1 2 x", spaced(int::<i32>)).unwrap_err());
+        assert_eq!(
+            "line 1, column 5: expected an integer, found 'x'",
+            error.to_string()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn parses_tokens() -> Result<()> {
+        let mut cursor = Cursor::new(1, "R48 rest");
+        assert_eq!('R', cursor.one_of(&['L', 'R'])?);
+        assert_eq!(48, cursor.int::<i32>()?);
+        cursor.skip_spaces();
+        assert_eq!("rest", cursor.word()?);
+        cursor.end()?;
+
+        let error = parse_error(Cursor::new(2, "X1").one_of(&['L', 'R']).unwrap_err());
+        assert_eq!(
+            "line 2, column 1: expected one of 'L', 'R', found 'X'",
+            error.to_string()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn splits_blocks_and_lines() -> Result<()> {
+        let text = "3-5\n10-14\n\n\n1\n5\n";
+        let blocks = blocks(text);
+        assert_eq!(2, blocks.len());
+        assert_eq!(
+            Block {
+                first_line: 5,
+                lines: vec!["1", "5"]
+            },
+            blocks[1]
+        );
+        assert_eq!(vec![3..=5, 10..=14], blocks[0].parse_lines(range::<u32>)?);
+        assert_eq!(vec![1, 5], blocks[1].parse_lines(int::<u32>)?);
+
+        let error = parse_error(lines(text, int::<u32>).unwrap_err());
+        assert_eq!((1, 2), (error.line, error.column));
+        Ok(())
+    }
+}
@@ -0,0 +1,434 @@
+//! Snapshots of a solution's state, exported as terminal frames or images to watch it run.
+//!
+//! Like logging, recording is off by default: `snapshot!` only checks an atomic and does not
+//! evaluate its state unless the day was enabled with [`enable`].
+
+use crate::{Grid, Position};
+use anyhow::*;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+pub type Rgb = [u8; 3];
+
+/// One cell of a frame: the character shown in a terminal, and the color of its pixels in images.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cell {
+    pub glyph: char,
+    pub color: Rgb,
+}
+
+impl Cell {
+    /// `glyph` with the color [`color_of`] gives it.
+    pub fn new(glyph: char) -> Self {
+        Self {
+            glyph,
+            color: color_of(glyph),
+        }
+    }
+}
+
+pub type Frame = Grid<Cell>;
+
+/// State that can be drawn as a frame.
+pub trait Render {
+    fn render(&self) -> Frame;
+}
+
+impl Render for Grid<char> {
+    fn render(&self) -> Frame {
+        self.map(|&glyph| Cell::new(glyph))
+    }
+}
+
+impl Render for Grid<bool> {
+    fn render(&self) -> Frame {
+        self.map(|&set| Cell::new(if set { '#' } else { '.' }))
+    }
+}
+
+impl Render for Frame {
+    fn render(&self) -> Frame {
+        self.clone()
+    }
+}
+
+/// Default color of the characters the puzzles use, other ones getting a color derived from their code.
+pub fn color_of(glyph: char) -> Rgb {
+    match glyph {
+        ' ' => [0, 0, 0],
+        '.' => [32, 32, 40],
+        '#' => [200, 200, 200],
+        '@' => [230, 180, 60],
+        'x' | 'X' => [200, 60, 60],
+        '^' => [80, 150, 255],
+        '|' => [110, 240, 120],
+        'S' => [255, 255, 255],
+        _ => {
+            let code = glyph as u32;
+            [
+                (80 + code * 37 % 176) as u8,
+                (80 + code * 61 % 176) as u8,
+                (80 + code * 97 % 176) as u8,
+            ]
+        }
+    }
+}
+
+/// How [`Scene::export`] writes frames.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// One text file per frame, colored with ANSI escape codes: `cat` it to a terminal.
+    Ansi,
+    /// One binary PPM image per frame.
+    Ppm,
+    /// One PNG image per frame.
+    Png,
+    /// A single animated GIF of every frame.
+    Gif,
+}
+
+impl Format {
+    pub fn extension(self) -> &'static str {
+        match self {
+            Format::Ansi => "ans",
+            Format::Ppm => "ppm",
+            Format::Png => "png",
+            Format::Gif => "gif",
+        }
+    }
+}
+
+impl FromStr for Format {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "ansi" => Ok(Format::Ansi),
+            "ppm" => Ok(Format::Ppm),
+            "png" => Ok(Format::Png),
+            "gif" => Ok(Format::Gif),
+            _ => bail!(
+                "invalid render format '{}', expected ansi, ppm, png or gif",
+                s
+            ),
+        }
+    }
+}
+
+impl Display for Format {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Format::Ansi => "ansi",
+            Format::Ppm => "ppm",
+            Format::Png => "png",
+            Format::Gif => "gif",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Frame as text, each cell colored with a 24-bit ANSI escape code.
+pub fn ansi(frame: &Frame) -> String {
+    let mut text = String::new();
+    for row in frame.rows() {
+        let mut color = None;
+        for cell in row {
+            if color != Some(cell.color) {
+                let [r, g, b] = cell.color;
+                text.push_str(&format!("\x1b[38;2;{};{};{}m", r, g, b));
+                color = Some(cell.color);
+            }
+            text.push(cell.glyph);
+        }
+        text.push_str("\x1b[0m\n");
+    }
+    text
+}
+
+/// RGB pixels of the frame, each cell drawn as a `scale` by `scale` square.
+fn pixels(frame: &Frame, scale: usize) -> Vec<u8> {
+    let mut pixels = Vec::with_capacity(frame.width() * frame.height() * scale * scale * 3);
+    for row in frame.rows() {
+        for _ in 0..scale {
+            for cell in row {
+                for _ in 0..scale {
+                    pixels.extend_from_slice(&cell.color);
+                }
+            }
+        }
+    }
+    pixels
+}
+
+/// Frame as a binary PPM image.
+pub fn ppm(frame: &Frame, scale: usize) -> Vec<u8> {
+    let mut image = format!(
+        "P6\n{} {}\n255\n",
+        frame.width() * scale,
+        frame.height() * scale
+    )
+    .into_bytes();
+    image.extend(pixels(frame, scale));
+    image
+}
+
+/// Frame as a PNG image.
+pub fn png(frame: &Frame, scale: usize) -> Result<Vec<u8>> {
+    let mut image = Vec::new();
+    let mut encoder = png::Encoder::new(
+        &mut image,
+        (frame.width() * scale).try_into()?,
+        (frame.height() * scale).try_into()?,
+    );
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(&pixels(frame, scale))?;
+    writer.finish()?;
+    Ok(image)
+}
+
+/// Frames as an animated GIF, `delay` hundredths of a second apart.
+///
+/// All frames must have the same size and use at most 256 colors between them.
+pub fn gif(frames: &[Frame], scale: usize, delay: u16) -> Result<Vec<u8>> {
+    let Some(first) = frames.first() else {
+        bail!("no frame to export");
+    };
+    let (width, height) = (first.width() * scale, first.height() * scale);
+    if let Some(other) = frames
+        .iter()
+        .find(|f| (f.width(), f.height()) != (first.width(), first.height()))
+    {
+        bail!(
+            "frames have different sizes: {}x{} and {}x{}",
+            first.width(),
+            first.height(),
+            other.width(),
+            other.height()
+        );
+    }
+
+    let mut colors = BTreeMap::new();
+    for (_, cell) in frames.iter().flat_map(Grid::iter) {
+        let next = colors.len();
+        colors.entry(cell.color).or_insert(next);
+    }
+    ensure!(
+        colors.len() <= 256,
+        "frames use {} colors, a GIF holds 256",
+        colors.len()
+    );
+    let mut palette = vec![[0; 3]; colors.len()];
+    for (&color, &index) in &colors {
+        palette[index] = color;
+    }
+
+    let mut image = Vec::new();
+    {
+        let mut encoder = gif::Encoder::new(
+            &mut image,
+            width.try_into()?,
+            height.try_into()?,
+            palette.as_flattened(),
+        )?;
+        encoder.set_repeat(gif::Repeat::Infinite)?;
+        for frame in frames {
+            let indices = frame.map(|cell| colors[&cell.color] as u8);
+            let mut pixels = Vec::with_capacity(width * height);
+            for row in indices.rows() {
+                for _ in 0..scale {
+                    for &index in row {
+                        pixels.extend(std::iter::repeat_n(index, scale));
+                    }
+                }
+            }
+
+            let mut frame =
+                gif::Frame::from_indexed_pixels(width as u16, height as u16, pixels, None);
+            frame.delay = delay;
+            encoder.write_frame(&frame)?;
+        }
+    }
+    Ok(image)
+}
+
+/// The frames a day recorded under one name, in order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scene {
+    pub day: u8,
+    pub name: String,
+    pub frames: Vec<Frame>,
+}
+
+impl Scene {
+    /// Writes the frames to `dir` (e.g. `frame-0001.png`), returning the written files.
+    pub fn export(&self, dir: &Path, format: Format, scale: usize) -> Result<Vec<PathBuf>> {
+        fs::create_dir_all(dir).with_context(|| format!("failed to create {}", dir.display()))?;
+        let write = |name: String, content: &[u8]| {
+            let path = dir.join(name);
+            fs::write(&path, content)
+                .with_context(|| format!("failed to write {}", path.display()))?;
+            Ok(path)
+        };
+
+        match format {
+            Format::Gif => Ok(vec![write(
+                format!("{}.{}", self.name, format.extension()),
+                &gif(&self.frames, scale, 10)?,
+            )?]),
+            _ => self
+                .frames
+                .iter()
+                .enumerate()
+                .map(|(i, frame)| {
+                    let content = match format {
+                        Format::Ansi => ansi(frame).into_bytes(),
+                        Format::Ppm => ppm(frame, scale),
+                        _ => png(frame, scale)?,
+                    };
+                    write(
+                        format!("frame-{:04}.{}", i + 1, format.extension()),
+                        &content,
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Whether each day records its snapshots, index 0 being used by code outside of a day module.
+static ENABLED: [AtomicBool; 26] = [const { AtomicBool::new(false) }; 26];
+
+/// Frames recorded so far, by day and scene name.
+static SCENES: Mutex<BTreeMap<(u8, String), Vec<Frame>>> = Mutex::new(BTreeMap::new());
+
+/// Starts recording the snapshots of `day`.
+pub fn enable(day: u8) {
+    if let Some(enabled) = ENABLED.get(day as usize) {
+        enabled.store(true, Ordering::Relaxed);
+    }
+}
+
+#[inline]
+pub fn enabled(module: &str) -> bool {
+    ENABLED[crate::log::day_of(module)].load(Ordering::Relaxed)
+}
+
+#[doc(hidden)]
+pub fn record(module: &str, scene: &str, state: &dyn Render) {
+    let day = crate::log::day_of(module) as u8;
+    let frame = state.render();
+    SCENES
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .entry((day, scene.to_string()))
+        .or_default()
+        .push(frame);
+}
+
+/// Removes and returns the scenes `day` recorded.
+pub fn take(day: u8) -> Vec<Scene> {
+    let mut scenes = SCENES.lock().unwrap_or_else(|e| e.into_inner());
+    let names = scenes
+        .keys()
+        .filter(|(d, _)| *d == day)
+        .cloned()
+        .collect::<Vec<_>>();
+    names
+        .into_iter()
+        .filter_map(|key| {
+            let frames = scenes.remove(&key)?;
+            Some(Scene {
+                day,
+                name: key.1,
+                frames,
+            })
+        })
+        .collect()
+}
+
+/// Frame of `grid` with `glyph` drawn over each of `positions`, e.g. to show beams or a path.
+pub fn overlay(
+    grid: &impl Render,
+    positions: impl IntoIterator<Item = Position>,
+    glyph: char,
+) -> Frame {
+    let mut frame = grid.render();
+    for position in positions {
+        if let Some(cell) = frame.get_mut(position) {
+            *cell = Cell::new(glyph);
+        }
+    }
+    frame
+}
+
+/// Records a frame of `state` in the named scene, when rendering is enabled for the day it is in.
+#[macro_export]
+macro_rules! snapshot {
+    ($scene:expr, $state:expr) => {
+        if $crate::render::enabled(module_path!()) {
+            $crate::render::record(module_path!(), $scene, &$state);
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame() -> Frame {
+        "@.\n.@\n".parse::<Grid<char>>().unwrap().render()
+    }
+
+    #[test]
+    fn colors_text_frames() {
+        let text = ansi(&frame());
+        assert_eq!(2, text.lines().count());
+        assert!(text.starts_with("\x1b[38;2;230;180;60m@\x1b[38;2;32;32;40m."));
+        assert!(text.ends_with("@\x1b[0m\n"));
+    }
+
+    #[test]
+    fn scales_images() -> Result<()> {
+        let image = ppm(&frame(), 2);
+        let header = b"P6\n4 4\n255\n";
+        assert_eq!(header, &image[..header.len()]);
+        assert_eq!(header.len() + 4 * 4 * 3, image.len());
+        assert_eq!(
+            [230, 180, 60, 230, 180, 60, 32, 32, 40],
+            image[header.len()..][..9]
+        );
+
+        assert!(png(&frame(), 2)?.starts_with(b"\x89PNG"));
+        let animation = gif(&[frame(), overlay(&frame(), [(0, 1)], '|')], 2, 10)?;
+        assert!(animation.starts_with(b"GIF89a"));
+        assert!(gif(&[frame(), Grid::new(1, 1, Cell::new('.'))], 1, 10).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn records_enabled_days_only() {
+        // Day 25 is not used anywhere else, since recording is global
+        let module = "adv_code_2025::days::day25";
+        assert!(!enabled(module));
+
+        enable(25);
+        assert!(enabled(module));
+        assert!(!enabled("adv_code_2025::days::day24"));
+        record(module, "waves", &frame());
+        record(module, "waves", &frame());
+        let scenes = take(25);
+        assert_eq!(1, scenes.len());
+        assert_eq!(
+            ("waves", 2),
+            (scenes[0].name.as_str(), scenes[0].frames.len())
+        );
+        assert!(take(25).is_empty());
+    }
+}
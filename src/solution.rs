@@ -0,0 +1,134 @@
+use crate::{Answer, Example, InputError, Param, Params};
+use anyhow::*;
+use std::any::Any;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// One of the two halves of a day's puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+impl FromStr for Part {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim() {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(anyhow!("invalid part '{}', expected 1 or 2", s)),
+        }
+    }
+}
+
+/// A day's puzzle: its input is parsed once, then both parts borrow the parsed model.
+///
+/// Days are registered with the `aoc` runner in [`crate::days`], as [`Puzzle`]s.
+pub trait Solution: Sync {
+    /// What `parse` turns the input into.
+    type Parsed: Any + Send + Sync;
+
+    /// Day of the month, starting at 1.
+    fn day(&self) -> u8;
+
+    /// The examples from the puzzle text, checked by `cargo test`, `aoc check` and `aoc --examples`.
+    fn examples(&self) -> Vec<Example> {
+        Vec::new()
+    }
+
+    /// Constants that differ between the examples and the real input, with their real-input defaults.
+    ///
+    /// Examples override them with [`Example::param`], and the runner with `--param name=value`.
+    fn params(&self) -> Vec<Param> {
+        Vec::new()
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed>;
+
+    fn part1(&self, input: &Self::Parsed, params: &Params) -> Result<Answer>;
+
+    fn part2(&self, _input: &Self::Parsed, _params: &Params) -> Result<Answer> {
+        Err(anyhow!("part 2 is not solved yet"))
+    }
+}
+
+/// A parsed input, as returned by [`Puzzle::parse`].
+pub type ParsedInput = Box<dyn Any + Send + Sync>;
+
+/// A [`Solution`] with its parsed type erased, so that days can be listed and run alike.
+pub trait Puzzle: Sync {
+    fn day(&self) -> u8;
+
+    fn examples(&self) -> Vec<Example>;
+
+    fn params(&self) -> Vec<Param>;
+
+    /// Parses `input`, tagging any [`InputError`] with the day.
+    fn parse(&self, input: &str) -> Result<ParsedInput>;
+
+    /// Solves `part` from an input returned by `parse`, tagging any [`InputError`] with the day and part.
+    fn solve_parsed(&self, part: Part, input: &ParsedInput, params: &Params) -> Result<Answer>;
+
+    fn solve(&self, part: Part, input: &str, params: &Params) -> Result<Answer> {
+        self.solve_parsed(part, &self.parse(input)?, params)
+    }
+}
+
+impl<S: Solution> Puzzle for S {
+    fn day(&self) -> u8 {
+        Solution::day(self)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        Solution::examples(self)
+    }
+
+    fn params(&self) -> Vec<Param> {
+        Solution::params(self)
+    }
+
+    fn parse(&self, input: &str) -> Result<ParsedInput> {
+        match Solution::parse(self, input) {
+            Result::Ok(parsed) => Ok(Box::new(parsed)),
+            Err(e) => Err(tag(e, Solution::day(self), None)),
+        }
+    }
+
+    fn solve_parsed(&self, part: Part, input: &ParsedInput, params: &Params) -> Result<Answer> {
+        let input = input
+            .downcast_ref::<S::Parsed>()
+            .ok_or_else(|| anyhow!("the input was not parsed by day {}", Solution::day(self)))?;
+        let result = match part {
+            Part::One => self.part1(input, params),
+            Part::Two => self.part2(input, params),
+        };
+        result.map_err(|e| tag(e, Solution::day(self), Some(part)))
+    }
+}
+
+fn tag(mut error: Error, day: u8, part: Option<Part>) -> Error {
+    if let Some(input_error) = error.downcast_mut::<InputError>() {
+        input_error.day = Some(day);
+        input_error.part = part;
+    }
+    error
+}
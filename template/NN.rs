@@ -0,0 +1,35 @@
+use crate::{Answer, Example, Params, Solution};
+use anyhow::*;
+
+const TEST: &str = "\
+<TEST-INPUT>
+"; // TODO: Add the test input
+
+pub struct DayNN; // TODO: Rename with the day number
+
+impl Solution for DayNN {
+    type Parsed = Vec<String>; // TODO: Pick what the input is parsed into
+
+    fn day(&self) -> u8 {
+        0 // TODO: Fill the day
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        // TODO: Set the expected answer for the test input, then add `.part2(...)` once solving part 2
+        vec![Example::new("main", TEST).part1(0)]
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        // TODO: Parse the input once (see `crate::parse`), both parts borrow the result
+        Ok(input.lines().map(str::to_string).collect())
+    }
+
+    fn part1(&self, lines: &Self::Parsed, _params: &Params) -> Result<Answer> {
+        // TODO: Solve Part 1 of the puzzle
+        Ok(lines.len().into())
+    }
+
+    // fn part2(&self, lines: &Self::Parsed, _params: &Params) -> Result<Answer> {
+    //     Ok(0.into())
+    // }
+}
@@ -0,0 +1,129 @@
+//! Every registered day's examples and real input as individual test cases.
+//!
+//! Each case is named `dayNN::partN_examples` or `dayNN::partN_real`, e.g.
+//! `cargo test --test regression day07::part2`. Cases that have nothing to
+//! compare (no input, no recorded answer) pass and print why they were skipped,
+//! visible with `-- --nocapture`.
+
+use adv_code_2025::*;
+
+fn solution(day: u8) -> &'static dyn Puzzle {
+    days::get(day).unwrap_or_else(|| panic!("day {:02} is not registered", day))
+}
+
+fn skip(day: u8, part: Part, case: &str, reason: String) {
+    eprintln!("skipped day {:02} part {} {}: {}", day, part, case, reason);
+}
+
+fn check_examples(day: u8, part: Part) {
+    let solution = solution(day);
+    let examples = solution.examples();
+    let examples = examples
+        .iter()
+        .filter(|e| e.expected(part).is_some())
+        .collect::<Vec<_>>();
+    if examples.is_empty() {
+        return skip(day, part, "examples", "no example for this part".into());
+    }
+
+    for example in examples {
+        let answer = example
+            .run(solution, part)
+            .unwrap_or_else(|e| panic!("example '{}' failed: {:#}", example.name, e));
+        assert_eq!(
+            example.expected(part),
+            Some(&answer),
+            "example '{}'",
+            example.name
+        );
+    }
+}
+
+fn check_real(day: u8, part: Part) {
+    let solution = solution(day);
+    let sheet = AnswerSheet::load(day).unwrap();
+    let Some(expected) = sheet.real.get(part) else {
+        return skip(
+            day,
+            part,
+            "real",
+            format!("no answer recorded in {}", AnswerSheet::path(day)),
+        );
+    };
+
+    // Never downloads, so that tests stay offline
+    let provider = InputProvider::new()
+        .with_source(EnvOverride)
+        .with_source(LocalDir::new("input"));
+    let input = match provider.load(day) {
+        Ok(input) => input,
+        Err(e) if e.is::<MissingInput>() => {
+            let reason = format!(
+                "no input in input/{:02}.txt or ${}",
+                day,
+                EnvOverride::variable(day)
+            );
+            return skip(day, part, "real", reason);
+        }
+        Err(e) => panic!("{:#}", e),
+    };
+
+    let params = Params::new(&solution.params());
+    let answer = solution
+        .solve(part, &input, &params)
+        .unwrap_or_else(|e| panic!("{:#}", e));
+    assert_eq!(expected, &answer);
+}
+
+macro_rules! regression {
+    ($($module:ident => $day:literal),* $(,)?) => {
+        const DAYS: &[u8] = &[$($day),*];
+
+        $(
+            mod $module {
+                use adv_code_2025::Part;
+
+                #[test]
+                fn part1_examples() {
+                    super::check_examples($day, Part::One);
+                }
+
+                #[test]
+                fn part1_real() {
+                    super::check_real($day, Part::One);
+                }
+
+                #[test]
+                fn part2_examples() {
+                    super::check_examples($day, Part::Two);
+                }
+
+                #[test]
+                fn part2_real() {
+                    super::check_real($day, Part::Two);
+                }
+            }
+        )*
+    };
+}
+
+regression! {
+    day01 => 1,
+    day02 => 2,
+    day03 => 3,
+    day04 => 4,
+    day05 => 5,
+    day06 => 6,
+    day07 => 7,
+    day08 => 8,
+    day09 => 9,
+    day10 => 10,
+    day11 => 11,
+    day12 => 12,
+}
+
+#[test]
+fn every_registered_day_is_covered() {
+    let registered = days::all().iter().map(|s| s.day()).collect::<Vec<_>>();
+    assert_eq!(registered, DAYS);
+}